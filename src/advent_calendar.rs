@@ -1,7 +1,9 @@
+use std::collections::BTreeMap;
+
 use bevy::{prelude::*, ui::FocusPolicy};
 use serde::{Serialize, Deserialize};
 
-use crate::CalenderState;
+use crate::{Screen, days, unlock::UnlockPolicy};
 
 pub struct AdventPlugin;
 
@@ -9,73 +11,264 @@ impl Plugin for AdventPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CalendarAssets>();
         app.init_resource::<AdventData>();
+        app.init_resource::<UnlockPolicy>();
+        app.init_resource::<CalendarYear>();
         app.add_system(advent_buttons);
-        app.add_system_set(SystemSet::on_enter(CalenderState::CalenderMenu)
+        app.add_system(update_stars);
+        app.add_system_set(SystemSet::on_enter(Screen::Calendar)
             .with_system(setup_calender)
+            .with_system(crate::stats::spawn_stats)
+        )
+        .add_system_set(SystemSet::on_update(Screen::Calendar)
+            .with_system(update_countdown)
+            .with_system(year_tabs)
         )
-        .add_system_set(SystemSet::on_pause(CalenderState::CalenderMenu)
+        .add_system_set(SystemSet::on_pause(Screen::Calendar)
             .with_system(super::hide_ui::<false, CalenderItem>)
         )
-        .add_system_set(SystemSet::on_resume(CalenderState::CalenderMenu)
+        .add_system_set(SystemSet::on_resume(Screen::Calendar)
             .with_system(super::hide_ui::<true, CalenderItem>)
             .with_system(update_stars)
         )
-        .add_system_set(SystemSet::on_exit(CalenderState::CalenderMenu)
+        .add_system_set(SystemSet::on_exit(Screen::Calendar)
             .with_system(super::cleanup::<CalenderItem>)
         );
     }
 }
 
+/// Everyone playing on this machine, the calendar and everything seeded from `rng_seed` follow the active profile.
 #[derive(Resource, Serialize, Deserialize)]
 pub struct AdventData {
+    active: usize,
+    profiles: Vec<Profile>,
+    /// From `--seed` or `AOB_SEED`, it is never saved.
+    #[serde(skip)]
+    pinned_seed: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Profile {
+    pub name: String,
     pub rng_seed: u64,
-    pub stars: Stars,
-    #[serde(skip_serializing)]
-    #[serde(default = "get_day")]
-    day: u8,
+    /// Each event has its own calendar of stars.
+    pub stars: BTreeMap<i32, Stars>,
+    #[serde(default)]
+    pub settings: Settings,
 }
 
-fn get_day() -> u8 {
-    use chrono::Datelike;
-    let now = chrono::Utc::now();
-    if now.month() == 12 {
-        now.date_naive().day0() as u8
-    } else {
-        30
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct Settings {
+    /// Opens every door no matter the date, on top of `AOB_UNLOCK_ALL`.
+    pub unlock_all: bool,
+    /// The speed recordings start playing at, changed with the timeline controls.
+    pub playback_speed: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings { unlock_all: false, playback_speed: 1. }
+    }
+}
+
+impl Profile {
+    pub fn new(name: impl Into<String>) -> Profile {
+        Profile { name: name.into(), rng_seed: rand::random(), stars: BTreeMap::new(), settings: Settings::default() }
+    }
+
+    /// The stars of one event, years that have not been played have none.
+    pub fn stars(&self, year: i32) -> &Stars {
+        self.stars.get(&year).unwrap_or(Stars::NONE)
+    }
+
+    pub fn is_gold(&self, star: &AdventStar) -> bool {
+        self.stars(star.year).is_gold(star)
+    }
+
+    pub fn set_gold(&mut self, star: &AdventStar) {
+        self.stars.entry(star.year).or_default().set_gold(star);
+    }
+}
+
+impl From<Profile> for AdventData {
+    fn from(profile: Profile) -> Self {
+        AdventData { active: 0, profiles: vec![profile], pinned_seed: None }
     }
 }
 
 impl AdventData {
-    fn new() -> AdventData {
-        AdventData {
-            rng_seed: rand::random(), stars: Stars::default(), day: get_day(),
+    pub(crate) fn new() -> AdventData {
+        Profile::new("Player 1").into()
+    }
+
+    pub(crate) fn from_profiles(active: usize, profiles: Vec<Profile>) -> AdventData {
+        AdventData { active, profiles, pinned_seed: None }.checked()
+    }
+
+    /// Saves edited by hand can have no profiles or point past the end.
+    pub(crate) fn checked(mut self) -> AdventData {
+        if self.profiles.is_empty() {
+            self.profiles.push(Profile::new("Player 1"));
         }
+        self.active = self.active.min(self.profiles.len() - 1);
+        self
+    }
+
+    pub fn profile(&self) -> &Profile {
+        &self.profiles[self.active]
+    }
+
+    pub fn profile_mut(&mut self) -> &mut Profile {
+        &mut self.profiles[self.active]
+    }
+
+    /// What everything is shuffled with, the pinned seed if there is one.
+    pub fn rng_seed(&self) -> u64 {
+        self.pinned_seed.unwrap_or(self.profile().rng_seed)
+    }
+
+    pub fn pinned_seed(&self) -> Option<u64> {
+        self.pinned_seed
+    }
+
+    /// Gives a profile a new seed, rerolling the active profile also drops the pin so the new layout shows.
+    pub fn reroll(&mut self, index: usize) {
+        let Some(profile) = self.profiles.get_mut(index) else {return;};
+        profile.rng_seed = rand::random();
+        if index == self.active {
+            self.pinned_seed = None;
+        }
+    }
+
+    pub fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    pub fn profiles_mut(&mut self) -> &mut [Profile] {
+        &mut self.profiles
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.profiles.len() {
+            self.active = index;
+        }
+    }
+
+    /// Adds a profile with a new seed and returns where it is.
+    pub fn create(&mut self) -> usize {
+        let name = self.unique_name(&format!("Player {}", self.profiles.len() + 1));
+        self.profiles.push(Profile::new(name));
+        self.profiles.len() - 1
+    }
+
+    /// Copies a profile, seed and stars and all, so someone can try things without touching the original.
+    pub fn duplicate(&mut self, index: usize) -> Option<usize> {
+        let mut profile = self.profiles.get(index)?.clone();
+        profile.name = self.unique_name(&format!("{} copy", profile.name));
+        self.profiles.insert(index + 1, profile);
+        if self.active > index {
+            self.active += 1;
+        }
+        Some(index + 1)
+    }
+
+    pub fn rename(&mut self, index: usize, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.profiles.iter().enumerate().any(|(i, p)| i != index && p.name == name) {return false;}
+        let Some(profile) = self.profiles.get_mut(index) else {return false;};
+        profile.name = name.to_string();
+        true
+    }
+
+    /// The last profile can not be deleted, there is always someone playing.
+    pub fn delete(&mut self, index: usize) -> bool {
+        if self.profiles.len() <= 1 || index >= self.profiles.len() {return false;}
+        self.profiles.remove(index);
+        if self.active > index || self.active == self.profiles.len() {
+            self.active -= 1;
+        }
+        true
+    }
+
+    fn unique_name(&self, name: &str) -> String {
+        let mut unique = name.to_string();
+        let mut n = 2;
+        while self.profiles.iter().any(|p| p.name == unique) {
+            unique = format!("{} {}", name, n);
+            n += 1;
+        }
+        unique
     }
 }
 
 impl FromWorld for AdventData {
-    fn from_world(_: &mut World) -> Self {
-        let Ok(data) = std::fs::read_to_string("./advent.dat") else {
-            let ad = AdventData::new();
-            let _ = std::fs::write("./advent.dat", ron::to_string(&ad).unwrap());
-            return ad;
-        };
-        ron::from_str(data.as_ref()).unwrap()
+    fn from_world(world: &mut World) -> Self {
+        let (mut advent_data, mode) = crate::save::load();
+        world.insert_resource(mode);
+        advent_data.pinned_seed = crate::seed::pinned();
+        advent_data
     }
 }
 
+/// For resources shuffled with `rng_seed`, builds them again when the seed changes.
+pub fn init_seeded<R: Resource + FromWorld>(app: &mut App) {
+    app.init_resource::<R>();
+    app.add_system(reseed::<R>);
+}
+
+fn reseed<R: Resource + FromWorld>(world: &mut World, mut seed: Local<Option<u64>>) {
+    let current = world.resource::<AdventData>().rng_seed();
+    if seed.replace(current).map_or(true, |seed| seed == current) {return;}
+    let resource = R::from_world(world);
+    world.insert_resource(resource);
+}
+
 #[derive(Component)]
-struct CalenderItem;
+pub(crate) struct CalenderItem;
 
-#[derive(Resource, Default, Serialize, Deserialize)]
-pub struct Stars([[bool; 2]; 25]);
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct Stars {
+    /// One entry per day, only as long as the last day with a star so it fits events of any length.
+    gold: Vec<[bool; 2]>,
+    /// Unix time each star was earned, stars from saves before this was kept have none.
+    earned: Vec<[Option<i64>; 2]>,
+}
 
 impl Stars {
+    const NONE: &Stars = &Stars { gold: Vec::new(), earned: Vec::new() };
+
+    /// Stars from saves that kept a fixed number of days.
+    pub(crate) fn from_days(gold: Vec<[bool; 2]>, mut earned: Vec<[Option<i64>; 2]>) -> Stars {
+        earned.resize(gold.len(), [None; 2]);
+        Stars { gold, earned }
+    }
     pub fn is_gold(&self, star: &AdventStar) -> bool {
-        self.0[star.day as usize][star.star as usize]
+        self.gold.get(star.day as usize).map_or(false, |day| day[star.star as usize])
     }
     pub fn set_gold(&mut self, star: &AdventStar) {
-        self.0[star.day as usize][star.star as usize] = true;
+        if self.is_gold(star) {return;}
+        let day = star.day as usize;
+        if self.gold.len() <= day {
+            self.gold.resize(day + 1, [false; 2]);
+            self.earned.resize(day + 1, [None; 2]);
+        }
+        self.gold[day][star.star as usize] = true;
+        self.earned[day][star.star as usize] = Some(chrono::Utc::now().timestamp());
+    }
+    pub fn earned(&self, star: &AdventStar) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+        self.earned.get(star.day as usize)?[star.star as usize].and_then(|time| chrono::Utc.timestamp_opt(time, 0).single())
+    }
+    pub fn count(&self) -> usize {
+        self.gold.iter().flatten().filter(|gold| **gold).count()
+    }
+    /// Both stars of `day`, counting from 0.
+    pub fn is_complete(&self, day: u8) -> bool {
+        self.gold.get(day as usize).map_or(false, |gold| gold.iter().all(|gold| *gold))
     }
 }
 
@@ -102,20 +295,41 @@ impl FromWorld for CalendarAssets {
 
 const BOXSIZE: f32 = 100.;
 
+/// The event shown on the calendar, picked with the tabs at the top.
+#[derive(Resource)]
+pub struct CalendarYear(pub i32);
+
+impl FromWorld for CalendarYear {
+    fn from_world(world: &mut World) -> Self {
+        let year = world.resource::<UnlockPolicy>().year;
+        // the event being played if it has days, otherwise the newest one before it
+        let shown = days::years().filter(|y| *y <= year).max().or_else(|| days::years().min()).unwrap_or(year);
+        CalendarYear(shown)
+    }
+}
+
 fn setup_calender(
     assets: Res<CalendarAssets>,
     mut commands: Commands,
     advent_data: Res<AdventData>,
+    solve_times: Res<days::SolveTimes>,
+    policy: Res<UnlockPolicy>,
+    year: Res<CalendarYear>,
 ){
+    let year = year.0;
+    let now = chrono::Utc::now();
     use rand::SeedableRng;
     use rand::seq::SliceRandom;
     let mut boxs = Vec::new();
-    let mut order = (0..25).collect::<Vec<u8>>();
-    let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed);
+    let count = days::day_count(year);
+    let mut order = (0..count).collect::<Vec<u8>>();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed());
     order.shuffle(&mut rng);
-    for i in order {
-        let state: CalenderState = CalenderState::from_day(i + 1);
-        boxs.push(commands.spawn((ButtonBundle {
+    for (slot, i) in order.into_iter().enumerate() {
+        let registered = days::registered(year, i + 1).is_some();
+        let unlocked = policy.is_unlocked(year, i + 1, now);
+        let color = if !registered {Color::GRAY} else if unlocked {Color::WHITE} else {Color::SILVER};
+        let mut door = commands.spawn((ButtonBundle {
             style: Style {
                 size: Size { width: Val::Px(100.), height: Val::Px(100.) },
                 margin: UiRect::right(Val::Px(5.)),
@@ -123,8 +337,13 @@ fn setup_calender(
                 justify_content: JustifyContent::SpaceAround,
                 ..default()
             },
+            background_color: color.into(),
             ..default()
-        }, state)).with_children(|p| {
+        }, Door { day: i + 1, slot: slot as u8, color }));
+        if registered {
+            door.insert(Screen::Day(year, i + 1));
+        }
+        boxs.push(door.with_children(|p| {
             p.spawn(TextBundle {
                 text: Text { sections: vec![TextSection {
                     value: format!("{:02}", i + 1),
@@ -140,32 +359,64 @@ fn setup_calender(
             focus_policy: FocusPolicy::Pass,
             ..Default::default()
             });
+            if !registered {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection {
+                        value: "not implemented".to_string(),
+                        style: TextStyle { font: assets.text_font.clone(), font_size: 15., color: Color::BLACK }
+                    }],
+                    alignment: TextAlignment::CENTER
+                    },
+                    focus_policy: FocusPolicy::Pass,
+                    ..Default::default()
+                });
+                return;
+            }
             p.spawn((ImageBundle {
-                image: if advent_data.day < i {assets.black_star.clone().into()} else if advent_data.stars.is_gold(&AdventStar { day: i, star: 0 }){assets.gold_star.clone().into()} else {assets.gray_star.clone().into()},
+                image: if !unlocked {assets.black_star.clone().into()} else if advent_data.profile().is_gold(&AdventStar { year, day: i, star: 0 }){assets.gold_star.clone().into()} else {assets.gray_star.clone().into()},
                 style: Style {
                     size: Size::new(Val::Px(10.), Val::Px(10.)),
                     ..default()
                 },
                 focus_policy: FocusPolicy::Pass,
                 ..default()
-            }, AdventStar{day: i, star: 0})
+            }, AdventStar{year, day: i, star: 0})
             );
             p.spawn((ImageBundle {
-                image: if advent_data.day < i {assets.black_star.clone().into()} else if advent_data.stars.is_gold(&AdventStar { day: i, star: 1 }) {assets.gold_star.clone().into()} else {assets.gray_star.clone().into()},
+                image: if !unlocked {assets.black_star.clone().into()} else if advent_data.profile().is_gold(&AdventStar { year, day: i, star: 1 }) {assets.gold_star.clone().into()} else {assets.gray_star.clone().into()},
                 style: Style {
                     size: Size::new(Val::Px(10.), Val::Px(10.)),
                     ..default()
                 },
                 focus_policy: FocusPolicy::Pass,
                 ..default()
-            }, AdventStar{day: i, star: 1}),
+            }, AdventStar{year, day: i, star: 1}),
             );
+            if let Some(timings) = solve_times.0.get(&(year, i + 1)) {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection {
+                        value: format!("{:.1?}", timings.total()),
+                        style: TextStyle { font: assets.text_font.clone(), font_size: 12., color: Color::DARK_GRAY }
+                    }],
+                    alignment: TextAlignment::default()
+                    },
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        position: UiRect::new(Val::Auto, Val::Px(2.), Val::Auto, Val::Px(0.)),
+                        ..default()
+                    },
+                    focus_policy: FocusPolicy::Pass,
+                    ..Default::default()
+                });
+            }
         }).id());
     }
+    let columns = doors_per_row(count);
+    let rows = (count + columns - 1) / columns;
     commands.spawn((NodeBundle {
         style: Style {
             position: UiRect { left: Val::Auto, right: Val::Auto, top: Val::Auto, bottom: Val::Auto },
-            size: Size { width: Val::Px((BOXSIZE + 5.) * 5.), height: Val::Px((BOXSIZE + 5.) * 5.) },
+            size: Size { width: Val::Px((BOXSIZE + 5.) * columns as f32), height: Val::Px((BOXSIZE + 5.) * rows as f32) },
             flex_wrap: FlexWrap::Wrap,
             align_content: AlignContent::SpaceBetween,
             align_self: AlignSelf::Center,
@@ -174,20 +425,121 @@ fn setup_calender(
         },
         ..Default::default()
     }, CalenderItem)).push_children(&boxs);
+    commands.spawn((TextBundle {
+        text: Text { sections: vec![TextSection {
+            value: String::new(),
+            style: TextStyle { font: assets.text_font.clone(), font_size: 30., color: Color::BLACK }
+        }],
+        alignment: TextAlignment::CENTER
+        },
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Percent(40.), Val::Auto, Val::Px(10.), Val::Auto),
+            ..default()
+        },
+        ..Default::default()
+    }, Countdown(policy.next_unlock(year, now).map(|(day, _)| day)), CalenderItem));
+    commands.spawn((NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Auto, Val::Px(10.), Val::Px(10.), Val::Auto),
+            ..default()
+        },
+        ..Default::default()
+    }, CalenderItem)).with_children(|p| {
+        for tab in days::years() {
+            p.spawn((ButtonBundle {
+                style: Style {
+                    padding: UiRect::horizontal(Val::Px(5.)),
+                    margin: UiRect::left(Val::Px(5.)),
+                    ..default()
+                },
+                background_color: if tab == year {Color::GOLD.into()} else {Color::GRAY.into()},
+                ..default()
+            }, YearTab(tab))).with_children(|p| {
+                p.spawn(TextBundle::from_section(tab.to_string(), TextStyle { font: assets.text_font.clone(), font_size: 30., color: Color::BLACK }));
+            });
+        }
+    });
+}
+
+#[derive(Component)]
+struct YearTab(i32);
+
+fn year_tabs(
+    query: Query<(&Interaction, &YearTab), Changed<Interaction>>,
+    mut year: ResMut<CalendarYear>,
+    mut state: ResMut<State<Screen>>,
+) {
+    for (interaction, tab) in &query {
+        if *interaction != Interaction::Clicked || tab.0 == year.0 {continue;}
+        year.0 = tab.0;
+        if let Err(e) = state.restart() {
+            warn!("Failed to show {}: {:?}", tab.0, e);
+        }
+        return;
+    }
+}
+
+/// Counts down to the door that opens next, it holds the day that was locked when the calendar was drawn.
+#[derive(Component)]
+struct Countdown(Option<u8>);
+
+fn update_countdown(
+    mut query: Query<(&mut Text, &Countdown)>,
+    policy: Res<UnlockPolicy>,
+    year: Res<CalendarYear>,
+    mut state: ResMut<State<Screen>>,
+) {
+    let now = chrono::Utc::now();
+    let next = policy.next_unlock(year.0, now);
+    for (mut text, countdown) in &mut query {
+        if countdown.0 != next.map(|(day, _)| day) {
+            // a door opened so the calendar is drawn again
+            if let Err(e) = state.restart() {
+                warn!("Failed to redraw the calendar: {:?}", e);
+            }
+            return;
+        }
+        text.sections[0].value = match next {
+            Some((day, left)) if left.num_days() > 0 => format!("Day {} unlocks in {}d {:02}:{:02}:{:02}", day, left.num_days(), left.num_hours() % 24, left.num_minutes() % 60, left.num_seconds() % 60),
+            Some((day, left)) => format!("Day {} unlocks in {:02}:{:02}:{:02}", day, left.num_hours(), left.num_minutes() % 60, left.num_seconds() % 60),
+            None => String::new(),
+        };
+    }
+}
+
+/// Where a door sits in the shuffled grid, so keys and the D-pad can move between them.
+#[derive(Component)]
+pub(crate) struct Door {
+    pub day: u8,
+    pub slot: u8,
+    pub color: Color,
+}
+
+/// Doors are laid out in rows of this many, as close to a square as `count` doors allow.
+pub(crate) fn doors_per_row(count: u8) -> u8 {
+    (1..=count).find(|columns| *columns as u16 * *columns as u16 >= count as u16).unwrap_or(1)
 }
 
 #[derive(Component)]
 pub struct AdventStar {
+    pub year: i32,
     pub day: u8,
     pub star: u8,
 }
 
 fn advent_buttons(
-    query: Query<(&Interaction, &CalenderState), (Changed<Interaction>, With<Button>)>,
-    mut res: ResMut<State<CalenderState>>,
+    query: Query<(&Interaction, &Screen), (Changed<Interaction>, With<Button>)>,
+    mut res: ResMut<State<Screen>>,
+    policy: Res<UnlockPolicy>,
 ){
     for (interaction, state) in &query {
         if interaction == &Interaction::Clicked {
+            if let Some((year, day)) = state.day().filter(|(year, day)| !policy.is_unlocked(*year, *day, chrono::Utc::now())) {
+                info!("Day {} of {} is still locked", day, year);
+                continue;
+            }
             info!("set state to {:?}", state);
             let _ = res.set(*state);
             return;
@@ -201,7 +553,7 @@ fn update_stars(
     data: Res<AdventData>,
 ) {
     for (mut image, star) in &mut query {
-        if data.stars.is_gold(star) {
+        if data.profile().is_gold(star) && image.0 != assets.gold_star {
             image.0 = assets.gold_star.clone();
         }
     }
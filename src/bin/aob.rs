@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+
+use advent_of_bevy::{days::{self, Day}, puzzle::{Bench, Stats}};
+use serde_json::json;
+
+const USAGE: &str = "Usage:
+    aob solve [--year <YEAR>] --day <DAY> [--part <1|2>] [--input <PATH>]
+    aob solve [--year <YEAR>] --all
+    aob bench [--year <YEAR>] --day <DAY> [--runs <N>] [--input <PATH>] [--json]
+    aob bench [--year <YEAR>] --all [--runs <N>] [--json]
+The year defaults to the newest one with days.";
+
+#[derive(PartialEq)]
+enum Command {
+    Solve,
+    Bench,
+}
+
+struct Args {
+    command: Command,
+    year: i32,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    all: bool,
+    runs: usize,
+    json: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let command = match args.next().as_deref() {
+        Some("solve") => Command::Solve,
+        Some("bench") => Command::Bench,
+        Some(other) => return Err(format!("Unknown command {}", other)),
+        None => return Err("Expected a command".to_string()),
+    };
+    let year = days::years().max().ok_or("There are no years with days")?;
+    let mut out = Args { command, year, day: None, part: None, input: None, all: false, runs: 10, json: false };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => out.year = next_value(&mut args, "--year")?.parse().map_err(|_| "--year needs to be a number")?,
+            "--day" => out.day = Some(next_value(&mut args, "--day")?.parse().map_err(|_| "--day needs to be a number")?),
+            "--part" if out.command == Command::Solve => out.part = Some(next_value(&mut args, "--part")?.parse().map_err(|_| "--part needs to be a number")?),
+            "--input" => out.input = Some(next_value(&mut args, "--input")?.into()),
+            "--all" => out.all = true,
+            "--runs" if out.command == Command::Bench => out.runs = next_value(&mut args, "--runs")?.parse().map_err(|_| "--runs needs to be a number")?,
+            "--json" if out.command == Command::Bench => out.json = true,
+            other => return Err(format!("Unknown argument {}", other)),
+        }
+    }
+    if out.all == out.day.is_some() {
+        return Err("Expected one of --day or --all".to_string());
+    }
+    if out.all && out.input.is_some() {
+        return Err("--input only works with --day".to_string());
+    }
+    Ok(out)
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, name: &str) -> Result<String, String> {
+    args.next().ok_or(format!("{} needs a value", name))
+}
+
+fn asset_root() -> PathBuf {
+    if let Ok(root) = std::env::var("BEVY_ASSET_ROOT").or(std::env::var("CARGO_MANIFEST_DIR")) {
+        PathBuf::from(root).join("assets")
+    } else {
+        let exe = std::env::current_exe().unwrap_or_default();
+        exe.parent().map(|p| p.join("assets")).unwrap_or_else(|| PathBuf::from("assets"))
+    }
+}
+
+fn read_input(year: i32, number: u8, input: &Option<PathBuf>) -> Result<String, String> {
+    if let Some(path) = input {
+        return std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e));
+    }
+    let path = asset_root().join(days::day_path(year, number));
+    let data = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut day = Day::from_ron(&data).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+    day.load_files(path.parent().unwrap_or(&asset_root()))?;
+    let base = asset_root().parent().map(PathBuf::from).unwrap_or_default();
+    day.load_private_input(&base, year, number)?;
+    // without a private input the first example is solved
+    day.puzzle_input().or(day.inputs.first()).map(|input| input.data.clone()).ok_or(format!("{} has no inputs", path.display()))
+}
+
+fn solve(year: i32, day: u8, part: u8, input: &Option<PathBuf>) -> Result<String, String> {
+    let solver = days::solver(year, day).ok_or(format!("Day {} of {} is not implemented", day, year))?;
+    solver(&read_input(year, day, input)?, part)
+}
+
+fn bench(year: i32, day: u8, runs: usize, input: &Option<PathBuf>) -> Result<Bench, String> {
+    let bencher = days::bencher(year, day).ok_or(format!("Day {} of {} is not implemented", day, year))?;
+    bencher(&read_input(year, day, input)?, runs).map_err(|e| e.to_string())
+}
+
+fn stats_json(stats: &Stats) -> serde_json::Value {
+    json!({
+        "min_ns": stats.min.as_nanos() as u64,
+        "median_ns": stats.median.as_nanos() as u64,
+        "max_ns": stats.max.as_nanos() as u64,
+    })
+}
+
+fn run_bench(args: &Args) {
+    let days: Vec<u8> = if args.all {days::solved_days(args.year).collect()} else {args.day.into_iter().collect()};
+    let mut results = Vec::new();
+    let mut failed = false;
+    for day in days {
+        match bench(args.year, day, args.runs, &args.input) {
+            Ok(bench) => results.push((day, bench)),
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+    if args.json {
+        let results: Vec<_> = results.iter().map(|(day, bench)| json!({
+            "year": args.year,
+            "day": day,
+            "runs": bench.runs,
+            "parse": stats_json(&bench.parse),
+            "part1": stats_json(&bench.part1),
+            "part2": stats_json(&bench.part2),
+        })).collect();
+        println!("{}", serde_json::to_string_pretty(&results).unwrap_or_default());
+    } else {
+        println!("{:>3} | {:<6} | {:>12} | {:>12} | {:>12}", "Day", "Step", "Min", "Median", "Max");
+        println!("{:-<3}-+-{:-<6}-+-{:->12}-+-{:->12}-+-{:->12}", "", "", "", "", "");
+        for (day, bench) in results.iter() {
+            for (step, stats) in [("parse", &bench.parse), ("part 1", &bench.part1), ("part 2", &bench.part2)] {
+                println!("{:>3} | {:<6} | {:>12} | {:>12} | {:>12}", day, step, format!("{:.2?}", stats.min), format!("{:.2?}", stats.median), format!("{:.2?}", stats.max));
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.command == Command::Bench {
+        run_bench(&args);
+        return;
+    }
+    if args.all {
+        println!("{:>3} | {:<20} | {:<20}", "Day", "Part 1", "Part 2");
+        println!("{:-<3}-+-{:-<20}-+-{:-<20}", "", "", "");
+        let mut failed = false;
+        for day in days::solved_days(args.year) {
+            let answers: Vec<String> = (1..=2).map(|part| solve(args.year, day, part, &None).unwrap_or_else(|e| {
+                failed = true;
+                e
+            })).collect();
+            println!("{:>3} | {:<20} | {:<20}", day, answers[0], answers[1]);
+        }
+        if failed {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let day = args.day.unwrap_or_default();
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    for part in parts {
+        match solve(args.year, day, part, &args.input) {
+            Ok(answer) => println!("Day {} Part {}: {}", day, part, answer),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
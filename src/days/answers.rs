@@ -0,0 +1,379 @@
+use bevy::{prelude::*, ecs::system::SystemParam, utils::HashMap};
+
+use crate::{puzzle::{Answers, Timings}, advent_calendar::{AdventData, AdventStar, CalendarAssets}};
+
+use super::Day;
+
+pub struct AnswersPlugin;
+
+impl Plugin for AnswersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SubmitAnswer>();
+        app.init_resource::<FocusedInput>();
+        app.init_resource::<SolveTimes>();
+        app.add_system(update_answers);
+        app.add_system(copy_answer);
+        app.add_system(focus_input);
+        app.add_system(unfocus_input);
+        app.add_system(type_answer);
+        app.add_system(submit_button);
+        app.add_system(show_input);
+        app.add_system(check_answers);
+    }
+}
+
+/// The answers of the day that was solved last, inserted by `plugin::finish_solve`.
+#[derive(Resource)]
+pub struct DayAnswers {
+    pub year: i32,
+    pub day: u8,
+    pub input: usize,
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+/// How long the last solve of each day took this session, shown on the calendar doors.
+#[derive(Resource, Default)]
+pub struct SolveTimes(pub HashMap<(i32, u8), Timings>);
+
+/// An answer the user typed in by hand.
+pub struct SubmitAnswer {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Component)]
+struct AnswerText {
+    day: u8,
+    part: u8,
+}
+
+#[derive(Component)]
+struct TimingText(u8);
+
+#[derive(Component)]
+struct CopyAnswer {
+    day: u8,
+    part: u8,
+}
+
+#[derive(Component)]
+pub struct AnswerInput {
+    year: i32,
+    day: u8,
+    part: u8,
+    value: String,
+}
+
+#[derive(Component)]
+struct SubmitButton(Entity);
+
+#[derive(Resource, Default)]
+pub struct FocusedInput(Option<Entity>);
+
+/// Lets other screens know when keys are going into an answer box.
+#[derive(SystemParam)]
+pub struct AnswerFocus<'w, 's> {
+    focused: Res<'w, FocusedInput>,
+    inputs: Query<'w, 's, (), With<AnswerInput>>,
+}
+
+impl AnswerFocus<'_, '_> {
+    pub fn is_typing(&self) -> bool {
+        self.focused.0.map_or(false, |entity| self.inputs.contains(entity))
+    }
+}
+
+pub(super) fn spawn_answers(p: &mut ChildBuilder, year: i32, day: u8, assets: &CalendarAssets) {
+    let font = assets.text_font.clone();
+    p.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Auto, Val::Px(0.), Val::Px(0.), Val::Auto),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(5.)),
+            ..Default::default()
+        },
+        background_color: Color::rgba(1., 1., 1., 0.8).into(),
+        ..Default::default()
+    }).with_children(|p| {
+        for part in 1..=2 {
+            p.spawn(NodeBundle {
+                style: Style {
+                    align_items: AlignItems::Center,
+                    margin: UiRect::vertical(Val::Px(2.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }).with_children(|p| {
+                p.spawn((ImageBundle {
+                    image: assets.gray_star.clone().into(),
+                    style: Style {
+                        size: Size::new(Val::Px(20.), Val::Px(20.)),
+                        margin: UiRect::right(Val::Px(5.)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }, AdventStar { year, day: day - 1, star: part - 1 }));
+                p.spawn((TextBundle {
+                    text: Text { sections: vec![TextSection {
+                        value: format!("Part {}: ", part),
+                        style: TextStyle { font: font.clone(), font_size: 25., color: Color::BLACK },
+                    }, TextSection {
+                        value: "...".to_string(),
+                        style: TextStyle { font: font.clone(), font_size: 25., color: Color::DARK_GREEN },
+                    }, TextSection {
+                        value: String::new(),
+                        style: TextStyle { font: font.clone(), font_size: 18., color: Color::DARK_GRAY },
+                    }], alignment: TextAlignment::default() },
+                    style: Style {
+                        min_size: Size::new(Val::Px(250.), Val::Auto),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }, AnswerText { day, part }));
+                p.spawn((ButtonBundle {
+                    style: Style {
+                        padding: UiRect::horizontal(Val::Px(5.)),
+                        ..Default::default()
+                    },
+                    background_color: Color::GRAY.into(),
+                    ..Default::default()
+                }, CopyAnswer { day, part })).with_children(|p| {
+                    p.spawn(TextBundle {
+                        text: Text { sections: vec![TextSection {
+                            value: "Copy".to_string(),
+                            style: TextStyle { font: font.clone(), font_size: 20., color: Color::BLACK },
+                        }], alignment: TextAlignment::CENTER },
+                        ..Default::default()
+                    });
+                });
+                let input = p.spawn((ButtonBundle {
+                    style: Style {
+                        min_size: Size::new(Val::Px(150.), Val::Auto),
+                        margin: UiRect::left(Val::Px(10.)),
+                        padding: UiRect::horizontal(Val::Px(5.)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }, AnswerInput { year, day, part, value: String::new() })).with_children(|p| {
+                    p.spawn(TextBundle {
+                        text: Text { sections: vec![TextSection {
+                            value: String::new(),
+                            style: TextStyle { font: font.clone(), font_size: 20., color: Color::BLACK },
+                        }], alignment: TextAlignment::default() },
+                        ..Default::default()
+                    });
+                }).id();
+                p.spawn((ButtonBundle {
+                    style: Style {
+                        padding: UiRect::horizontal(Val::Px(5.)),
+                        ..Default::default()
+                    },
+                    background_color: Color::GRAY.into(),
+                    ..Default::default()
+                }, SubmitButton(input))).with_children(|p| {
+                    p.spawn(TextBundle {
+                        text: Text { sections: vec![TextSection {
+                            value: "Submit".to_string(),
+                            style: TextStyle { font: font.clone(), font_size: 20., color: Color::BLACK },
+                        }], alignment: TextAlignment::CENTER },
+                        ..Default::default()
+                    });
+                });
+            });
+        }
+        p.spawn((TextBundle {
+            text: Text { sections: vec![TextSection {
+                value: String::new(),
+                style: TextStyle { font: font.clone(), font_size: 18., color: Color::DARK_GRAY },
+            }], alignment: TextAlignment::default() },
+            ..Default::default()
+        }, TimingText(day)));
+    });
+}
+
+/// Answers are marked against the ones the input expects, so examples show if they match.
+fn update_answers(
+    answers: Option<Res<DayAnswers>>,
+    asset_server: Res<AssetServer>,
+    days: Res<Assets<Day>>,
+    mut texts: Query<(&mut Text, &AnswerText)>,
+    mut timing_texts: Query<(&mut Text, &TimingText), Without<AnswerText>>,
+    added: Query<(), Added<AnswerText>>,
+) {
+    let Some(answers) = answers else {return;};
+    if !answers.is_changed() && added.is_empty() {return;}
+    let day = days.get(&asset_server.load(&super::day_path(answers.year, answers.day)));
+    let input = day.and_then(|d| d.inputs.get(answers.input));
+    for (mut text, answer) in &mut texts {
+        if answer.day != answers.day {continue;}
+        let value = answers.answers.part(answer.part);
+        text.sections[1].value = value.unwrap_or("...").to_string();
+        let expected = input.and_then(|i| i.answers.part(answer.part));
+        let (marker, color) = match (value, expected) {
+            (Some(value), Some(expected)) if value == expected => (" matches".to_string(), Color::DARK_GREEN),
+            (Some(_), Some(expected)) => (format!(" expected {}", expected), Color::RED),
+            _ => (String::new(), Color::DARK_GRAY),
+        };
+        text.sections[2].value = marker;
+        text.sections[2].style.color = color;
+    }
+    let t = answers.timings;
+    for (mut text, timing) in &mut timing_texts {
+        if timing.0 != answers.day {continue;}
+        text.sections[0].value = format!("Parse {:.2?} | Part 1 {:.2?} | Part 2 {:.2?}", t.parse, t.part1, t.part2);
+    }
+}
+
+fn copy_answer(
+    query: Query<(&Interaction, &CopyAnswer), Changed<Interaction>>,
+    answers: Option<Res<DayAnswers>>,
+) {
+    let Some(answers) = answers else {return;};
+    for (interaction, copy) in &query {
+        if *interaction != Interaction::Clicked || copy.day != answers.day {continue;}
+        let Some(answer) = answers.answers.part(copy.part) else {continue;};
+        match arboard::Clipboard::new().and_then(|mut c| c.set_text(answer.to_string())) {
+            Ok(()) => info!("Copied Part {} answer {}", copy.part, answer),
+            Err(e) => warn!("Failed to copy answer: {}", e),
+        }
+    }
+}
+
+fn focus_input(
+    query: Query<(Entity, &Interaction), (Changed<Interaction>, With<AnswerInput>)>,
+    mut focused: ResMut<FocusedInput>,
+) {
+    for (entity, interaction) in &query {
+        if *interaction == Interaction::Clicked {
+            focused.0 = Some(entity);
+        }
+    }
+}
+
+/// Clicking anywhere that is not an answer box lets go of the focus.
+fn unfocus_input(
+    mouse: Res<Input<MouseButton>>,
+    inputs: Query<&Interaction, With<AnswerInput>>,
+    mut focused: ResMut<FocusedInput>,
+) {
+    if focused.0.is_none() || !mouse.just_pressed(MouseButton::Left) {return;}
+    if inputs.iter().all(|interaction| *interaction != Interaction::Clicked) {
+        focused.0 = None;
+    }
+}
+
+fn type_answer(
+    mut chars: EventReader<ReceivedCharacter>,
+    mut keys: ResMut<Input<KeyCode>>,
+    mut focused: ResMut<FocusedInput>,
+    mut inputs: Query<&mut AnswerInput>,
+    mut submit: EventWriter<SubmitAnswer>,
+) {
+    let Some(mut input) = focused.0.and_then(|e| inputs.get_mut(e).ok()) else {chars.clear(); return;};
+    // the keys are used up here so leaving the box does not also leave the day
+    if keys.clear_just_pressed(KeyCode::Escape) {
+        focused.0 = None;
+        return;
+    }
+    for c in chars.iter() {
+        if !c.char.is_control() {
+            input.value.push(c.char);
+        }
+    }
+    if keys.just_pressed(KeyCode::Back) {
+        input.value.pop();
+    }
+    if keys.clear_just_pressed(KeyCode::Return) | keys.clear_just_pressed(KeyCode::NumpadEnter) {
+        submit.send(SubmitAnswer { year: input.year, day: input.day, part: input.part, answer: input.value.clone() });
+        focused.0 = None;
+    }
+}
+
+fn submit_button(
+    query: Query<(&Interaction, &SubmitButton), Changed<Interaction>>,
+    inputs: Query<&AnswerInput>,
+    mut submit: EventWriter<SubmitAnswer>,
+) {
+    for (interaction, button) in &query {
+        if *interaction != Interaction::Clicked {continue;}
+        let Ok(input) = inputs.get(button.0) else {continue;};
+        submit.send(SubmitAnswer { year: input.year, day: input.day, part: input.part, answer: input.value.clone() });
+    }
+}
+
+fn show_input(
+    focused: Res<FocusedInput>,
+    inputs: Query<(Entity, &AnswerInput, &Children)>,
+    changed: Query<(), Changed<AnswerInput>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, input, children) in &inputs {
+        if !focused.is_changed() && !changed.contains(entity) {continue;}
+        for child in children.iter() {
+            let Ok(mut text) = texts.get_mut(*child) else {continue;};
+            text.sections[0].value = if focused.0 == Some(entity) {
+                format!("{}|", input.value)
+            } else if input.value.is_empty() {
+                "your answer".to_string()
+            } else {
+                input.value.clone()
+            };
+        }
+    }
+}
+
+fn check_answers(
+    mut submissions: EventReader<SubmitAnswer>,
+    solved: Option<Res<DayAnswers>>,
+    asset_server: Res<AssetServer>,
+    days: Res<Assets<Day>>,
+    mut advent_data: ResMut<AdventData>,
+) {
+    let mut checks = Vec::new();
+    if let Some(solved) = solved.as_ref().filter(|s| s.is_changed()) {
+        let day = days.get(&asset_server.load(&super::day_path(solved.year, solved.day)));
+        // only the puzzle input earns stars, examples are marked in `update_answers`
+        if let Some(input) = day.and_then(|d| d.inputs.get(solved.input)).filter(|i| !i.example) {
+            for part in 1..=2 {
+                if let Some(answer) = solved.answers.part(part) {
+                    checks.push((solved.year, solved.day, part, answer.to_string(), input.answers.part(part).map(str::to_string), false));
+                }
+            }
+        }
+    }
+    for submission in submissions.iter() {
+        if submission.answer.trim().is_empty() {continue;}
+        let day = days.get(&asset_server.load(&super::day_path(submission.year, submission.day)));
+        let stored = day.and_then(|d| d.puzzle_input()).and_then(|i| i.answers.part(submission.part));
+        // without a stored answer the typed one is checked against the last solve of the puzzle input
+        let computed = || solved.as_ref()
+            .filter(|s| s.year == submission.year && s.day == submission.day)
+            .filter(|s| day.and_then(|d| d.inputs.get(s.input)).map_or(false, |i| !i.example))
+            .and_then(|s| s.answers.part(submission.part));
+        let expected = stored.or_else(computed).map(str::to_string);
+        checks.push((submission.year, submission.day, submission.part, submission.answer.trim().to_string(), expected, true));
+    }
+    for (year, day, part, answer, expected, by_hand) in checks {
+        let correct = match expected {
+            Some(expected) => expected == answer,
+            None if by_hand => {
+                warn!("Day {} Part {} of {} has no answer to check {} against, taking it as right", day, part, year, answer);
+                true
+            },
+            None => false,
+        };
+        let star = AdventStar { year, day: day - 1, star: part - 1 };
+        if correct {
+            if !advent_data.profile().is_gold(&star) {
+                info!("Day {} Part {} of {} is solved with {}", day, part, year, answer);
+                advent_data.profile_mut().set_gold(&star);
+            }
+        } else if by_hand {
+            warn!("{} is not the answer to Day {} Part {}", answer, day, part);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use bevy::{prelude::*, input::mouse::{MouseScrollUnit, MouseWheel}};
+
+use crate::advent_calendar::{AdventData, AdventStar, CalendarAssets};
+
+use super::Day;
+
+pub struct DescriptionPlugin;
+
+impl Plugin for DescriptionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(toggle_description);
+        app.add_system(scroll_description);
+        app.add_system(unlock_part2);
+    }
+}
+
+const WIDTH: f32 = 500.;
+const HEIGHT: f32 = 350.;
+const FONT_SIZE: f32 = 20.;
+
+/// The header of a description panel, clicking it shows or hides the `DescriptionBody` next to it.
+#[derive(Component)]
+struct DescriptionToggle;
+
+/// Part 2 stays locked until the part 1 star is gold.
+#[derive(Component)]
+struct Locked {
+    year: i32,
+    day: u8,
+}
+
+#[derive(Component)]
+struct DescriptionBody;
+
+/// How far the text in a `DescriptionBody` has been scrolled.
+#[derive(Component, Default)]
+struct Scroll(f32);
+
+pub(super) fn spawn_descriptions(p: &mut ChildBuilder, year: i32, day_number: u8, day: &Day, assets: &CalendarAssets, advent_data: &AdventData) {
+    if day.description.is_empty() && day.description_2.is_empty() {return;}
+    p.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Px(0.), Val::Auto, Val::Px(110.), Val::Auto),
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        },
+        z_index: ZIndex::Global(1),
+        ..Default::default()
+    }).with_children(|p| {
+        if !day.description.is_empty() {
+            spawn_description(p, "Part 1".to_string(), &day.description, assets, None);
+        }
+        if !day.description_2.is_empty() {
+            let unlocked = advent_data.profile().is_gold(&AdventStar { year, day: day_number - 1, star: 0 });
+            spawn_description(p, "Part 2".to_string(), &day.description_2, assets, if unlocked {None} else {Some(Locked { year, day: day_number })});
+        }
+    });
+}
+
+fn spawn_description(p: &mut ChildBuilder, title: String, text: &str, assets: &CalendarAssets, locked: Option<Locked>) {
+    p.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            margin: UiRect::bottom(Val::Px(5.)),
+            ..Default::default()
+        },
+        background_color: Color::rgba(1., 1., 1., 0.9).into(),
+        ..Default::default()
+    }).with_children(|p| {
+        let mut header = p.spawn((ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Px(WIDTH), Val::Auto),
+                padding: UiRect::all(Val::Px(5.)),
+                ..Default::default()
+            },
+            background_color: Color::GRAY.into(),
+            ..Default::default()
+        }, DescriptionToggle));
+        header.with_children(|p| {
+            p.spawn(TextBundle {
+                text: Text { sections: vec![TextSection {
+                    value: header_text(&title, locked.is_some()),
+                    style: TextStyle { font: assets.text_font.clone(), font_size: 25., color: Color::BLACK },
+                }], alignment: TextAlignment::default() },
+                ..Default::default()
+            });
+        });
+        if let Some(locked) = locked {
+            header.insert(locked);
+        }
+        p.spawn((NodeBundle {
+            style: Style {
+                display: Display::None,
+                size: Size::new(Val::Px(WIDTH), Val::Auto),
+                max_size: Size::new(Val::Undefined, Val::Px(HEIGHT)),
+                overflow: Overflow::Hidden,
+                ..Default::default()
+            },
+            ..Default::default()
+        }, Interaction::default(), DescriptionBody)).with_children(|p| {
+            p.spawn((NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    flex_shrink: 0.,
+                    padding: UiRect::all(Val::Px(5.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }, Scroll::default())).with_children(|p| {
+                for line in text.lines() {
+                    p.spawn(TextBundle {
+                        text: Text { sections: format_line(line, assets), alignment: TextAlignment::default() },
+                        style: Style {
+                            max_size: Size::new(Val::Px(WIDTH - 10.), Val::Undefined),
+                            min_size: Size::new(Val::Auto, Val::Px(FONT_SIZE)),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    });
+                }
+            });
+        });
+    });
+}
+
+fn header_text(title: &str, locked: bool) -> String {
+    if locked {
+        format!("{} unlocks with the Part 1 star", title)
+    } else {
+        format!("{} (click to show)", title)
+    }
+}
+
+/// A small bit of markdown: `- ` starts a bullet, `*emphasis*` and `` `code` `` change the colour of the text they wrap.
+fn format_line(line: &str, assets: &CalendarAssets) -> Vec<TextSection> {
+    let style = |color: Color| TextStyle { font: assets.text_font.clone(), font_size: FONT_SIZE, color };
+    let (mut sections, line) = match line.trim_start().strip_prefix("- ") {
+        Some(rest) => (vec![TextSection { value: "  - ".to_string(), style: style(Color::BLACK) }], rest),
+        None => (Vec::new(), line),
+    };
+    let mut current = String::new();
+    let mut marker = None;
+    for char in line.chars() {
+        match (char, marker) {
+            ('*' | '`', None) => {
+                if !current.is_empty() {
+                    sections.push(TextSection { value: std::mem::take(&mut current), style: style(Color::BLACK) });
+                }
+                marker = Some(char);
+            },
+            (c, Some(m)) if c == m => {
+                let color = if m == '`' {Color::MIDNIGHT_BLUE} else {Color::rgb(0.6, 0.4, 0.)};
+                sections.push(TextSection { value: std::mem::take(&mut current), style: style(color) });
+                marker = None;
+            },
+            (c, _) => current.push(c),
+        }
+    }
+    // an unclosed marker is just text
+    if let Some(m) = marker {
+        current.insert(0, m);
+    }
+    sections.push(TextSection { value: current, style: style(Color::BLACK) });
+    sections
+}
+
+fn toggle_description(
+    headers: Query<(&Interaction, &Parent), (With<DescriptionToggle>, Without<Locked>, Changed<Interaction>)>,
+    children: Query<&Children>,
+    mut bodies: Query<&mut Style, With<DescriptionBody>>,
+) {
+    for (interaction, parent) in &headers {
+        if *interaction != Interaction::Clicked {continue;}
+        let Ok(children) = children.get(parent.get()) else {continue;};
+        for child in children {
+            if let Ok(mut style) = bodies.get_mut(*child) {
+                style.display = if style.display == Display::None {Display::Flex} else {Display::None};
+            }
+        }
+    }
+}
+
+fn scroll_description(
+    mut wheel: EventReader<MouseWheel>,
+    bodies: Query<(&Interaction, &Node, &Children), With<DescriptionBody>>,
+    mut content: Query<(&mut Scroll, &mut Style, &Node)>,
+) {
+    for event in wheel.iter() {
+        let dy = match event.unit {
+            MouseScrollUnit::Line => event.y * FONT_SIZE,
+            MouseScrollUnit::Pixel => event.y,
+        };
+        for (interaction, body, children) in &bodies {
+            if *interaction == Interaction::None {continue;}
+            for child in children {
+                let Ok((mut scroll, mut style, node)) = content.get_mut(*child) else {continue;};
+                let max = (node.size().y - body.size().y).max(0.);
+                scroll.0 = (scroll.0 + dy).clamp(-max, 0.);
+                style.position.top = Val::Px(scroll.0);
+            }
+        }
+    }
+}
+
+/// Unlocks part 2 when the part 1 star is earned while the day is open.
+fn unlock_part2(
+    mut commands: Commands,
+    advent_data: Res<AdventData>,
+    locked: Query<(Entity, &Locked, &Children)>,
+    mut text: Query<&mut Text>,
+) {
+    if !advent_data.is_changed() {return;}
+    for (entity, lock, children) in &locked {
+        if !advent_data.profile().is_gold(&AdventStar { year: lock.year, day: lock.day - 1, star: 0 }) {continue;}
+        commands.entity(entity).remove::<Locked>();
+        for child in children {
+            if let Ok(mut text) = text.get_mut(*child) {
+                text.sections[0].value = header_text("Part 2", false);
+            }
+        }
+    }
+}
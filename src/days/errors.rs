@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+use crate::puzzle::ParseError;
+
+use super::DayItem;
+
+/// Lines shown either side of the one that failed.
+const CONTEXT: usize = 2;
+/// Long lines like day 6's signal are cut down to this many chars around the error.
+const MAX_WIDTH: usize = 60;
+
+pub(super) fn spawn_parse_error(commands: &mut Commands, error: &ParseError, input: &str, font: Handle<Font>) {
+    let style = |size: f32, color: Color| TextStyle { font: font.clone(), font_size: size, color };
+    commands.spawn((NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Percent(10.), Val::Auto, Val::Px(150.), Val::Auto),
+            size: Size::new(Val::Percent(80.), Val::Auto),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(10.)),
+            ..Default::default()
+        },
+        background_color: Color::rgba(0.2, 0., 0., 0.9).into(),
+        ..Default::default()
+    }, DayItem)).with_children(|p| {
+        p.spawn(TextBundle {
+            text: Text { sections: vec![TextSection {
+                value: format!("Day {} failed to parse", error.day),
+                style: style(35., Color::WHITE),
+            }], alignment: TextAlignment::default() },
+            ..Default::default()
+        });
+        p.spawn(TextBundle {
+            text: Text { sections: vec![TextSection {
+                value: format!("Line {} column {}: {}", error.line, error.column, error.message),
+                style: style(25., Color::ORANGE),
+            }], alignment: TextAlignment::default() },
+            style: Style {
+                margin: UiRect::bottom(Val::Px(10.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let first = error.line.saturating_sub(CONTEXT).max(1);
+        for (number, line) in input.lines().enumerate().map(|(i, line)| (i + 1, line)).skip(first - 1).take(error.line + CONTEXT + 1 - first) {
+            let (before, at, after) = split_at_column(line, if number == error.line {error.column} else {0});
+            p.spawn(NodeBundle {
+                background_color: if number == error.line {Color::rgba(1., 0., 0., 0.4).into()} else {Color::NONE.into()},
+                ..Default::default()
+            }).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection {
+                        value: format!("{:>4} | {}", number, before),
+                        style: style(20., Color::WHITE),
+                    }, TextSection {
+                        value: at,
+                        style: style(20., Color::YELLOW),
+                    }, TextSection {
+                        value: after,
+                        style: style(20., Color::WHITE),
+                    }], alignment: TextAlignment::default() },
+                    ..Default::default()
+                });
+            });
+        }
+    });
+}
+
+/// Splits a line around the char at `column` so it can be highlighted, a column of 0 highlights nothing.
+fn split_at_column(line: &str, column: usize) -> (String, String, String) {
+    let chars: Vec<char> = line.chars().collect();
+    let at = column.saturating_sub(1);
+    let start = if chars.len() > MAX_WIDTH {at.saturating_sub(MAX_WIDTH / 2).min(chars.len() - MAX_WIDTH)} else {0};
+    let end = (start + MAX_WIDTH).min(chars.len());
+    let collect = |from: usize, to: usize| chars[from.min(to)..to].iter().collect::<String>();
+    if column == 0 {
+        let mut line = collect(start, end);
+        if end < chars.len() {
+            line.push_str("...");
+        }
+        return (line, String::new(), String::new());
+    }
+    let mut before = collect(start, at.min(end));
+    if start > 0 {
+        before.insert_str(0, "...");
+    }
+    let highlight = chars.get(at).map(|c| if c.is_whitespace() {'_'} else {*c}).unwrap_or('_');
+    let mut after = collect((at + 1).min(end), end);
+    if end < chars.len() {
+        after.push_str("...");
+    }
+    (before, highlight.to_string(), after)
+}
@@ -0,0 +1,192 @@
+use bevy::{prelude::*, utils::HashMap, asset::FileAssetIo};
+
+use crate::{Screen, advent_calendar::CalendarAssets, puzzle::Answers};
+
+use super::{Day, DayInput, sources};
+
+pub struct InputsPlugin;
+
+impl Plugin for InputsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedInputs>();
+        app.add_system(select_input);
+        app.add_system(drop_input);
+        app.add_system(save_dropped);
+    }
+}
+
+/// Which of a days inputs is being looked at, days that have not been picked use their puzzle input.
+/// Files dropped on a day screen are kept here for the session and sit one past the days own inputs.
+#[derive(Resource, Default)]
+pub struct SelectedInputs {
+    selected: HashMap<(i32, u8), usize>,
+    dropped: HashMap<(i32, u8), DayInput>,
+}
+
+impl SelectedInputs {
+    pub fn index(&self, year: i32, day_number: u8, day: &Day) -> usize {
+        match self.selected.get(&(year, day_number)) {
+            Some(index) if *index < day.inputs.len() => *index,
+            Some(index) if *index == day.inputs.len() && self.dropped.contains_key(&(year, day_number)) => *index,
+            _ => day.inputs.iter().position(|input| !input.example).unwrap_or(0),
+        }
+    }
+
+    pub fn get<'a>(&'a self, year: i32, day_number: u8, day: &'a Day) -> Option<(usize, &'a DayInput)> {
+        let index = self.index(year, day_number, day);
+        day.inputs.get(index).or_else(|| self.dropped.get(&(year, day_number))).map(|input| (index, input))
+    }
+
+    pub fn dropped(&self, year: i32, day_number: u8) -> Option<&DayInput> {
+        self.dropped.get(&(year, day_number))
+    }
+}
+
+#[derive(Component)]
+struct InputSelector {
+    year: i32,
+    day: u8,
+    index: usize,
+}
+
+#[derive(Component)]
+struct SaveDropped(i32, u8);
+
+pub(super) fn spawn_input_selector(p: &mut ChildBuilder, year: i32, day_number: u8, day: &Day, selected: &SelectedInputs, assets: &CalendarAssets) {
+    let index = selected.index(year, day_number, day);
+    let font = assets.text_font.clone();
+    p.spawn(NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Px(110.), Val::Auto, Val::Px(0.), Val::Auto),
+            ..Default::default()
+        },
+        ..Default::default()
+    }).with_children(|p| {
+        let inputs = day.inputs.iter().chain(selected.dropped(year, day_number));
+        for (i, input) in inputs.enumerate() {
+            p.spawn((ButtonBundle {
+                style: Style {
+                    padding: UiRect::horizontal(Val::Px(5.)),
+                    margin: UiRect::right(Val::Px(5.)),
+                    ..Default::default()
+                },
+                background_color: if i == index {Color::GOLD.into()} else {Color::GRAY.into()},
+                ..Default::default()
+            }, InputSelector { year, day: day_number, index: i })).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection {
+                        value: input.name.clone(),
+                        style: TextStyle { font: font.clone(), font_size: 20., color: Color::BLACK },
+                    }], alignment: TextAlignment::CENTER },
+                    ..Default::default()
+                });
+            });
+        }
+        if index == day.inputs.len() && selected.dropped(year, day_number).is_some() {
+            p.spawn((ButtonBundle {
+                style: Style {
+                    padding: UiRect::horizontal(Val::Px(5.)),
+                    ..Default::default()
+                },
+                background_color: Color::GREEN.into(),
+                ..Default::default()
+            }, SaveDropped(year, day_number))).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection {
+                        value: "Save to inputs".to_string(),
+                        style: TextStyle { font: font.clone(), font_size: 20., color: Color::BLACK },
+                    }], alignment: TextAlignment::CENTER },
+                    ..Default::default()
+                });
+            });
+        }
+        if let Some((_, input)) = selected.get(year, day_number, day) {
+            p.spawn(TextBundle {
+                text: Text { sections: vec![TextSection {
+                    value: format!("from {}", input.source_name()),
+                    style: TextStyle { font: font.clone(), font_size: 20., color: Color::DARK_GRAY },
+                }], alignment: TextAlignment::default() },
+                style: Style {
+                    margin: UiRect::left(Val::Px(5.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        }
+    });
+}
+
+fn select_input(
+    query: Query<(&Interaction, &InputSelector), Changed<Interaction>>,
+    mut selected: ResMut<SelectedInputs>,
+    mut state: ResMut<State<Screen>>,
+) {
+    for (interaction, selector) in &query {
+        if *interaction != Interaction::Clicked {continue;}
+        selected.selected.insert((selector.year, selector.day), selector.index);
+        if let Err(e) = state.restart() {
+            warn!("Failed to reload Day {}: {:?}", selector.day, e);
+        }
+    }
+}
+
+fn drop_input(
+    mut events: EventReader<FileDragAndDrop>,
+    mut selected: ResMut<SelectedInputs>,
+    mut state: ResMut<State<Screen>>,
+    asset_server: Res<AssetServer>,
+    days: Res<Assets<Day>>,
+) {
+    for event in events.iter() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = event else {continue;};
+        let Some((year, day_number)) = state.current().day() else {info!("Open a day before dropping an input on it"); continue;};
+        let Some(day) = days.get(&asset_server.load(&super::day_path(year, day_number))) else {error!("Day {} of {} not loaded", day_number, year); continue;};
+        let data = match std::fs::read_to_string(path_buf) {
+            Ok(data) => data,
+            Err(e) => {error!("Failed to read {}: {}", path_buf.display(), e); continue;},
+        };
+        let name = path_buf.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or("Dropped".to_string());
+        info!("Using {} as the input for Day {}", path_buf.display(), day_number);
+        selected.dropped.insert((year, day_number), DayInput { name, example: false, answers: Answers::default(), data, file: None, source: Some(path_buf.clone()) });
+        selected.selected.insert((year, day_number), day.inputs.len());
+        if let Err(e) = state.restart() {
+            warn!("Failed to reload Day {}: {:?}", day_number, e);
+        }
+    }
+}
+
+/// Writes the dropped input to the private `inputs` folder so it is used from now on,
+/// any answers saved for the old input are removed as they belonged to it.
+fn save_dropped(
+    query: Query<(&Interaction, &SaveDropped), Changed<Interaction>>,
+    mut selected: ResMut<SelectedInputs>,
+    asset_server: Res<AssetServer>,
+    mut days: ResMut<Assets<Day>>,
+) {
+    for (interaction, SaveDropped(year, day_number)) in &query {
+        if *interaction != Interaction::Clicked {continue;}
+        let key = (*year, *day_number);
+        let Some(day) = days.get_mut(&asset_server.load(&super::day_path(*year, *day_number))) else {error!("Day {} of {} not loaded", day_number, year); continue;};
+        let Some(input) = selected.dropped.get(&key) else {continue;};
+        let base = FileAssetIo::get_base_path();
+        let file = day.inputs.iter().find_map(|input| input.source.clone()).unwrap_or_else(|| base.join(sources::INPUTS_DIR).join(sources::input_file(*year, *day_number)));
+        let written = file.parent().map_or(Ok(()), std::fs::create_dir_all).and_then(|_| std::fs::write(&file, &input.data));
+        if let Err(e) = written {
+            error!("Failed to save Day {} input to {}: {}", day_number, file.display(), e);
+            continue;
+        }
+        info!("Saved Day {} input to {}", day_number, file.display());
+        let answers = sources::answers_path(&file);
+        if answers.exists() {
+            if let Err(e) = std::fs::remove_file(&answers) {
+                warn!("Failed to remove old answers {}: {}", answers.display(), e);
+            }
+        }
+        // changing the asset restarts the day through `reload_day`
+        day.inputs.retain(|input| input.source.is_none());
+        day.inputs.push(DayInput { name: "Puzzle".to_string(), source: Some(file), ..input.clone() });
+        selected.dropped.remove(&key);
+        selected.selected.remove(&key);
+    }
+}
@@ -1,26 +1,38 @@
-use bevy::{prelude::*, asset::{AssetLoader, LoadedAsset, LoadContext}, app::PluginGroupBuilder, reflect::TypeUuid};
-use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
-use crate::{advent_calendar::{CalendarAssets}, CalenderState};
+use bevy::{prelude::*, asset::{AssetLoader, AssetPath, FileAssetIo, LoadedAsset, LoadContext}, reflect::TypeUuid};
+use serde::Deserialize;
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
+use crate::{advent_calendar::{AdventData, CalendarAssets}, Screen, puzzle::{self, Answers, Bencher, Puzzle, Solver}};
+
+use sources::InputData;
+
+mod answers;
+mod description;
+mod errors;
+mod inputs;
+mod plugin;
+pub mod sources;
+pub mod timeline;
+mod year2022;
+
+pub use answers::{AnswerFocus, DayAnswers, SolveTimes, SubmitAnswer};
+pub use inputs::SelectedInputs;
+pub use plugin::{DayPlugin, Visualise, add_day};
 
 pub struct DaysPlugin;
-struct DayPlugin;
+struct DayAssetPlugin;
 
-impl Plugin for DayPlugin {
+impl Plugin for DayAssetPlugin {
     fn build(&self, app: &mut App) {
         app.add_asset::<Day>();
         app.add_asset_loader(DayLoader);
+        app.add_asset::<InputFile>();
+        app.add_asset_loader(InputLoader);
         app.init_resource::<Days>();
-        app.add_system(update_button);
+        app.add_system(reload_day);
+        app.add_system(reload_input_files);
+        app.add_system(plugin::spin);
     }
 }
 
@@ -30,46 +42,187 @@ struct Days(Vec<HandleUntyped>);
 impl FromWorld for Days {
     fn from_world(world: &mut World) -> Self {
         let ass = world.resource::<AssetServer>();
-        Days(ass.load_folder("days").unwrap())
+        Days(years().flat_map(|year| ass.load_folder(&format!("{}/days", year)).unwrap()).collect())
     }
 }
 
-impl PluginGroup for DaysPlugin {
-    fn build(self) -> PluginGroupBuilder {
-        PluginGroupBuilder::start::<Self>()
-        .add(DayPlugin)
-        .add(day1::DayPlugin)
-        .add(day2::DayPlugin)
-        .add(day3::DayPlugin)
-        .add(day4::DayPlugin)
-        .add(day5::DayPlugin)
-        .add(day6::DayPlugin)
-        .add(day7::DayPlugin)
-        .add(day8::DayPlugin)
+impl Plugin for DaysPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(DayAssetPlugin);
+        app.add_plugin(answers::AnswersPlugin);
+        app.add_plugin(description::DescriptionPlugin);
+        app.add_plugin(timeline::TimelinePlugin);
+        app.add_plugin(inputs::InputsPlugin);
+        for day in YEARS.iter().flat_map(|year| year.days) {
+            (day.plugin)(app);
+        }
+    }
+}
+
+/// What a day module registers about itself, a new day only needs its `mod` line and an entry in its years `INFO`.
+pub struct DayInfo {
+    pub year: i32,
+    pub number: u8,
+    pub name: &'static str,
+    pub solver: Solver,
+    pub bench: Bencher,
+    pub plugin: fn(&mut App),
+}
+
+impl DayInfo {
+    /// Everything is worked out from the days `Puzzle` and `Visualise` types.
+    pub const fn new<const YEAR: i32, const DAY: u8, P: Puzzle + Send + 'static, V: Visualise<P>>(name: &'static str) -> DayInfo {
+        DayInfo {
+            year: YEAR,
+            number: DAY,
+            name,
+            solver: puzzle::solve::<P>,
+            bench: puzzle::bench::<P>,
+            plugin: add_day::<YEAR, DAY, P, V>,
+        }
     }
 }
 
-#[derive(Deserialize, Serialize, TypeUuid)]
+/// The days of one event, each year has its own module and its own folder in `assets`.
+pub struct YearInfo {
+    pub year: i32,
+    /// How many doors the calendar has, days without a module show as not implemented.
+    pub day_count: u8,
+    pub days: &'static [DayInfo],
+}
+
+/// Events used to always run to the 25th.
+pub const DEFAULT_DAY_COUNT: u8 = 25;
+
+pub const YEARS: &[YearInfo] = &[
+    year2022::INFO,
+];
+
+pub fn years() -> impl Iterator<Item = i32> {
+    YEARS.iter().map(|info| info.year)
+}
+
+pub fn day_count(year: i32) -> u8 {
+    YEARS.iter().find(|info| info.year == year).map_or(DEFAULT_DAY_COUNT, |info| info.day_count)
+}
+
+pub fn registered(year: i32, day: u8) -> Option<&'static DayInfo> {
+    YEARS.iter().find(|info| info.year == year)?.days.iter().find(|info| info.number == day)
+}
+
+/// Where a days asset is, relative to `assets`.
+pub fn day_path(year: i32, day: u8) -> String {
+    format!("{}/days/day{}.day.ron", year, day)
+}
+
+#[derive(Deserialize, TypeUuid, Clone)]
 #[uuid="711e45de-e047-4c73-8383-86c0b8c1773e"]
-struct Day {
-    tital: String,
-    data: String,
+pub struct Day {
+    pub tital: String,
+    /// Part 1 of the puzzle text, see `description.rs` for the formatting it supports.
+    #[serde(default)]
+    pub description: String,
+    /// Only shown once the part 1 star is gold.
+    #[serde(default)]
+    pub description_2: String,
+    pub inputs: Vec<DayInput>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(from = "RawDayInput")]
+pub struct DayInput {
+    pub name: String,
+    pub example: bool,
+    pub answers: Answers,
+    pub data: String,
+    /// Set when the asset has `data: File("path")`, `data` is filled in from that file when the day is loaded.
+    pub file: Option<String>,
+    /// Where the data was read from when it is not part of the day asset.
+    pub source: Option<PathBuf>,
+}
+
+/// A `DayInput` as it is written in a `.day.ron`.
+#[derive(Deserialize)]
+struct RawDayInput {
+    name: String,
+    #[serde(default)]
+    example: bool,
+    #[serde(default)]
+    answers: Answers,
+    data: InputData,
+}
+
+impl From<RawDayInput> for DayInput {
+    fn from(raw: RawDayInput) -> Self {
+        let (data, file) = match raw.data {
+            InputData::Inline(data) => (data, None),
+            InputData::File(file) => (String::new(), Some(file)),
+        };
+        DayInput { name: raw.name, example: raw.example, answers: raw.answers, data, file, source: None }
+    }
+}
+
+impl DayInput {
+    pub fn source_name(&self) -> String {
+        match &self.source {
+            Some(path) => path.display().to_string(),
+            None => "the day asset".to_string(),
+        }
+    }
+}
+
+impl Day {
+    /// Reads the text of a `.day.ron`, use this over `ron::from_str` so `data:` gets checked.
+    pub fn from_ron(text: &str) -> Result<Day, String> {
+        sources::check_input_data(text)?;
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// The first input that is not an example, this is the one stars are awarded for.
+    pub fn puzzle_input(&self) -> Option<&DayInput> {
+        self.inputs.iter().find(|input| !input.example)
+    }
+
+    /// Fills in the inputs that are kept in their own file, for when the day is read without the `AssetServer`.
+    pub fn load_files(&mut self, dir: &Path) -> Result<(), String> {
+        for input in self.inputs.iter_mut() {
+            let Some(file) = &input.file else {continue;};
+            let path = dir.join(file);
+            input.data = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+
+    /// Adds the private puzzle input from `sources` if there is one, without it the day falls back to its examples.
+    pub fn load_private_input(&mut self, base: &Path, year: i32, day: u8) -> Result<(), String> {
+        if let Some(input) = sources::load_input(base, year, day)? {
+            self.inputs.push(input);
+        }
+        Ok(())
+    }
+}
+
+pub fn solver(year: i32, day: u8) -> Option<Solver> {
+    registered(year, day).map(|info| info.solver)
+}
+
+pub fn bencher(year: i32, day: u8) -> Option<Bencher> {
+    registered(year, day).map(|info| info.bench)
+}
+
+pub fn solved_days(year: i32) -> impl Iterator<Item = u8> {
+    YEARS.iter().filter(move |info| info.year == year).flat_map(|info| info.days).map(|info| info.number)
 }
 
-fn spawn_day<const DAY: u8>(
+fn spawn_day<const YEAR: i32, const DAY: u8>(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     days: Res<Assets<Day>>,
     assets: Res<CalendarAssets>,
-    // advent_data: Res<AdventData>,
+    selected: Res<SelectedInputs>,
+    advent_data: Res<AdventData>,
 ) {
-    let Some(day) = days.get(&asset_server.load(&format!("days/day{}.day.ron", DAY))) else {error!("Day {} not loaded", DAY); return;};
-    //let des = vec![spawn_description(&mut commands, "Click to See Task 1".to_string(), day.description, assets.text_font.clone())];
-    // if advent_data.stars.is_gold(&AdventStar {day: DAY - 1, star: 0}) {
-    //     des.push(
-    //         spawn_description(&mut commands, "Click to See Task 2".to_string(), day.description_2, assets.text_font.clone())
-    //     );
-    // }
+    let Some(day) = days.get(&asset_server.load(&day_path(YEAR, DAY))) else {error!("Day {} of {} not loaded", DAY, YEAR); return;};
     commands.spawn((NodeBundle{
         style: Style {
             position_type: PositionType::Absolute,
@@ -90,7 +243,7 @@ fn spawn_day<const DAY: u8>(
                 ..Default::default()
             },
             ..Default::default()
-        }, CalenderState::CalenderMenu));
+        }, Screen::Calendar));
         p.spawn(TextBundle {
             text: Text { sections: vec![TextSection {
                 value: day.tital.clone(),
@@ -102,15 +255,15 @@ fn spawn_day<const DAY: u8>(
             },
             ..Default::default()
         });
+        answers::spawn_answers(p, YEAR, DAY, &assets);
+        inputs::spawn_input_selector(p, YEAR, DAY, day, &selected, &assets);
+        description::spawn_descriptions(p, YEAR, DAY, day, &assets, &advent_data);
     });
 }
 
 #[derive(Component)]
 struct DayItem;
 
-#[derive(Component)]
-struct Content;
-
 struct DayLoader;
 
 impl AssetLoader for DayLoader {
@@ -119,7 +272,7 @@ impl AssetLoader for DayLoader {
         bytes: &'a [u8],
         load_context: &'a mut bevy::asset::LoadContext,
     ) -> bevy::utils::BoxedFuture<'a, Result<(), bevy::asset::Error>> {
-        Box::pin(async move { load_day(bytes, load_context) })
+        Box::pin(async move { load_day(bytes, load_context).await })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -127,54 +280,85 @@ impl AssetLoader for DayLoader {
     }
 }
 
-fn load_day<'a>(bytes: &'a [u8], load_context: &'a mut LoadContext) -> Result<(), bevy::asset::Error> {
-    let mut day = ron::Deserializer::from_bytes(bytes)?;
-        let day = Day::deserialize(&mut day)?;
-        load_context.set_default_asset(LoadedAsset::new(day));
-        Ok(())
+async fn load_day<'a>(bytes: &'a [u8], load_context: &'a mut LoadContext<'_>) -> Result<(), bevy::asset::Error> {
+    let mut day = Day::from_ron(std::str::from_utf8(bytes)?).map_err(bevy::asset::Error::msg)?;
+    let dir = load_context.path().parent().map(Path::to_path_buf).unwrap_or_default();
+    let mut dependencies = Vec::new();
+    for input in day.inputs.iter_mut() {
+        let Some(file) = &input.file else {continue;};
+        let path = dir.join(file);
+        input.data = String::from_utf8(load_context.read_asset_bytes(&path).await?)?;
+        dependencies.push(AssetPath::new(path, None));
+    }
+    // days live at `<year>/days/day<number>.day.ron`
+    let path = load_context.path();
+    let number = path.file_name().and_then(|name| name.to_str()).and_then(|name| name.strip_prefix("day")?.strip_suffix(".day.ron")?.parse().ok());
+    let year = path.parent().and_then(Path::parent).and_then(Path::file_name).and_then(|name| name.to_str()?.parse().ok());
+    if let (Some(year), Some(number)) = (year, number) {
+        if let Err(e) = day.load_private_input(&FileAssetIo::get_base_path(), year, number) {
+            error!("{}", e);
+        }
+    }
+    load_context.set_default_asset(LoadedAsset::new(day).with_dependencies(dependencies));
+    Ok(())
 }
-fn spawn_description(
-    commands: &mut Commands,
-    short: String,
-    val: String,
-    font: Handle<Font>,
-) -> Entity {
-    commands.spawn(NodeBundle{z_index: ZIndex::Global(-1), ..Default::default()}).with_children(|p| {
-        p.spawn(ButtonBundle{style: Style {position_type: PositionType::Absolute, ..Default::default()}, ..Default::default()}).with_children(|p| {
-            p.spawn(TextBundle{
-                text: Text { sections: vec![TextSection {
-                    value: short,
-                    style: TextStyle { font: font.clone(), font_size: 25., color: Color::BLACK },
-                }], alignment: TextAlignment::default() },
-                ..Default::default()
-            });
-        });
-        p.spawn(ButtonBundle{style: Style {position_type: PositionType::Absolute, ..Default::default()},visibility: Visibility{is_visible: false},  ..Default::default()}).with_children(|p| {
-            p.spawn(TextBundle{
-                text: Text { sections: vec![TextSection {
-                    value: val,
-                    style: TextStyle { font: font.clone(), font_size: 20., color: Color::BLACK },
-                }], alignment: TextAlignment::default() },
-                ..Default::default()
-            });
-        });
-    }).id()
+
+/// The text of an input kept out of its `.day.ron`, days copy it in when they load.
+#[derive(TypeUuid)]
+#[uuid="3f4b2a8e-5c1d-4e7a-9b6f-2d8c0a1e7f53"]
+pub struct InputFile(pub String);
+
+struct InputLoader;
+
+impl AssetLoader for InputLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut bevy::asset::LoadContext,
+    ) -> bevy::utils::BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            load_context.set_default_asset(LoadedAsset::new(InputFile(String::from_utf8(bytes.to_vec())?)));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt", "input"]
+    }
 }
 
-fn update_button(
-    mut vis: Query<&mut Visibility>,
-    query: Query<(&Parent, &Interaction), (With<Button>, Changed<Interaction>)>,
-    children: Query<&Children>,
+/// Days only hold a copy of their `File` inputs, so any day using a file that changed is loaded again.
+fn reload_input_files(
+    mut events: EventReader<AssetEvent<InputFile>>,
+    asset_server: Res<AssetServer>,
+    days: Res<Assets<Day>>,
 ) {
-    for (p, i) in &query {
-        if *i == Interaction::Clicked {
-            for c in children.get(p.get()).expect("Parent to have childeren") {
-                if let Ok(mut vis) = vis.get_mut(*c) {
-                    vis.is_visible = !vis.is_visible;
-                } else {
-                    warn!("Child did not have vis");
-                }
+    for event in events.iter() {
+        let AssetEvent::Modified { handle } = event else {continue;};
+        let Some(changed) = asset_server.get_handle_path(handle) else {continue;};
+        for (id, day) in days.iter() {
+            let Some(day_path) = asset_server.get_handle_path(id) else {continue;};
+            let dir = day_path.path().parent().unwrap_or(Path::new(""));
+            if day.inputs.iter().filter_map(|input| input.file.as_ref()).any(|file| dir.join(file) == changed.path()) {
+                info!("{} changed, reloading {}", changed.path().display(), day_path.path().display());
+                asset_server.reload_asset(day_path.path());
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Restarts the open day when its asset changes on disk, so it is drawn again with the new inputs.
+fn reload_day(
+    mut events: EventReader<AssetEvent<Day>>,
+    mut state: ResMut<State<Screen>>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some((year, day_number)) = state.current().day() else {events.clear(); return;};
+    let handle: Handle<Day> = asset_server.load(&day_path(year, day_number));
+    if events.iter().any(|event| matches!(event, AssetEvent::Modified { handle: changed } if *changed == handle)) {
+        info!("Day {} of {} changed, solving it again", day_number, year);
+        if let Err(e) = state.restart() {
+            warn!("Failed to reload Day {} of {}: {:?}", day_number, year, e);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+
+use bevy::{prelude::*, ecs::system::{SystemParam, SystemParamItem, StaticSystemParam}, tasks::{AsyncComputeTaskPool, Task}};
+use futures::FutureExt;
+
+use crate::{Screen, advent_calendar::CalendarAssets, puzzle::{self, Answers, ParseError, Puzzle, Timings}};
+
+use super::{Day, DayAnswers, DayItem, SelectedInputs, SolveTimes};
+
+/// Draws a solved day, anything spawned at the top level needs a `DayItem` so it is cleaned up when the day is left.
+pub trait Visualise<P>: Send + Sync + 'static {
+    type Param: SystemParam + 'static;
+
+    /// Anything the day needs set up once, like the images it draws with.
+    fn build(_app: &mut App) {}
+
+    fn visualise(puzzle: P, commands: &mut Commands, param: SystemParamItem<Self::Param>);
+}
+
+/// For days that only show their answers.
+impl<P> Visualise<P> for () {
+    type Param = ();
+
+    fn visualise(_: P, _: &mut Commands, _: ()) {}
+}
+
+/// Everything a day screen needs, the day only provides its `Puzzle` and how to draw it.
+pub struct DayPlugin<const YEAR: i32, const DAY: u8, P, V>(PhantomData<fn() -> (P, V)>);
+
+impl<const YEAR: i32, const DAY: u8, P, V> Default for DayPlugin<YEAR, DAY, P, V> {
+    fn default() -> Self {
+        DayPlugin(PhantomData)
+    }
+}
+
+impl<const YEAR: i32, const DAY: u8, P: Puzzle + Send + 'static, V: Visualise<P>> Plugin for DayPlugin<YEAR, DAY, P, V> {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(Screen::Day(YEAR, DAY))
+        .with_system(super::spawn_day::<YEAR, DAY>)
+        .with_system(start_solve::<YEAR, DAY, P>))
+        .add_system_set(SystemSet::on_update(Screen::Day(YEAR, DAY))
+        .with_system(finish_solve::<YEAR, DAY, P, V>))
+        .add_system_set(SystemSet::on_exit(Screen::Day(YEAR, DAY))
+        .with_system(crate::cleanup::<DayItem>));
+        V::build(app);
+    }
+}
+
+/// Used as the `plugin` of a days `DayInfo`.
+pub fn add_day<const YEAR: i32, const DAY: u8, P: Puzzle + Send + 'static, V: Visualise<P>>(app: &mut App) {
+    app.add_plugin(DayPlugin::<YEAR, DAY, P, V>::default());
+}
+
+/// A solve running on the `AsyncComputeTaskPool`. It sits on the spinner which is a `DayItem`,
+/// so leaving the day despawns it and the answers are never shown, the solve itself still runs to the end on its thread.
+#[derive(Component)]
+struct Solving<P: Send + 'static> {
+    task: Task<Result<(P, Answers, Timings), ParseError>>,
+    input: usize,
+    data: String,
+}
+
+#[derive(Component)]
+pub(super) struct Spinner {
+    started: f32,
+}
+
+fn start_solve<const YEAR: i32, const DAY: u8, P: Puzzle + Send + 'static>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    days: Res<Assets<Day>>,
+    selected: Res<SelectedInputs>,
+    assets: Res<CalendarAssets>,
+    time: Res<Time>,
+) {
+    let Some(day) = days.get(&asset_server.load(&super::day_path(YEAR, DAY))) else {error!("Day {} of {} not loaded", DAY, YEAR); return;};
+    let Some((index, input)) = selected.get(YEAR, DAY, day) else {error!("Day {} of {} has no inputs", DAY, YEAR); return;};
+    commands.remove_resource::<DayAnswers>();
+    let data = input.data.clone();
+    let task = AsyncComputeTaskPool::get().spawn(async move {puzzle::timed::<P>(&data)});
+    commands.spawn((TextBundle {
+        text: Text { sections: vec![TextSection {
+            value: "Solving".to_string(),
+            style: TextStyle { font: assets.text_font.clone(), font_size: 40., color: Color::BLACK },
+        }], alignment: TextAlignment::CENTER },
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Percent(45.), Val::Auto, Val::Px(150.), Val::Auto),
+            ..Default::default()
+        },
+        ..Default::default()
+    }, DayItem, Spinner { started: time.elapsed_seconds() }, Solving { task, input: index, data: input.data.clone() }));
+}
+
+fn finish_solve<const YEAR: i32, const DAY: u8, P: Puzzle + Send + 'static, V: Visualise<P>>(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut Solving<P>)>,
+    assets: Res<CalendarAssets>,
+    mut solve_times: ResMut<SolveTimes>,
+    param: StaticSystemParam<V::Param>,
+) {
+    let Some((entity, mut solving)) = tasks.iter_mut().next() else {return;};
+    let Some(result) = (&mut solving.task).now_or_never() else {return;};
+    commands.entity(entity).despawn_recursive();
+    match result {
+        Ok((puzzle, answers, timings)) => {
+            info!("Day {} of {} took {:.2?}", DAY, YEAR, timings.total());
+            solve_times.0.insert((YEAR, DAY), timings);
+            commands.insert_resource(DayAnswers { year: YEAR, day: DAY, input: solving.input, answers, timings });
+            V::visualise(puzzle, &mut commands, param.into_inner());
+        },
+        Err(e) => {
+            error!("{}", e);
+            super::errors::spawn_parse_error(&mut commands, &e, &solving.data, assets.text_font.clone());
+        }
+    }
+}
+
+pub(super) fn spin(
+    mut spinners: Query<(&mut Text, &Spinner)>,
+    time: Res<Time>,
+) {
+    const FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+    let now = time.elapsed_seconds();
+    for (mut text, spinner) in &mut spinners {
+        text.sections[0].value = format!("Solving {} {:.1}s", FRAMES[(now * 8.) as usize % FRAMES.len()], now - spinner.started);
+    }
+}
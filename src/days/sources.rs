@@ -0,0 +1,179 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Deserializer, de::{self, SeqAccess, Visitor}};
+
+use crate::puzzle::Answers;
+
+use super::DayInput;
+
+/// Real puzzle inputs are not meant to be shared so they are kept out of `assets`,
+/// either in this git ignored folder next to `assets` or in the data directory.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where a days input goes under an inputs folder, each year has its own folder like the assets.
+pub fn input_file(year: i32, day: u8) -> PathBuf {
+    PathBuf::from(year.to_string()).join(format!("day{}.txt", day))
+}
+
+/// Everywhere a days input is looked for, in the order they are checked.
+pub fn input_paths(base: &Path, year: i32, day: u8) -> Vec<PathBuf> {
+    let file = input_file(year, day);
+    let mut paths = vec![base.join(INPUTS_DIR).join(&file)];
+    if let Some(dir) = dirs::data_dir() {
+        paths.push(dir.join("advent_of_bevy").join(INPUTS_DIR).join(&file));
+    }
+    paths
+}
+
+/// The answers for an input can sit next to it as `dayN.answers.ron`, without them the stars are only given for answers typed in.
+pub fn answers_path(input: &Path) -> PathBuf {
+    input.with_extension("answers.ron")
+}
+
+/// Reads the first private input found for a day, `None` means the day only has the examples in its asset.
+pub fn load_input(base: &Path, year: i32, day: u8) -> Result<Option<DayInput>, String> {
+    let Some(path) = input_paths(base, year, day).into_iter().find(|path| path.exists()) else {return Ok(None);};
+    let data = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let answers = match std::fs::read_to_string(answers_path(&path)) {
+        Ok(answers) => ron::from_str(&answers).map_err(|e| format!("Failed to parse {}: {}", answers_path(&path).display(), e))?,
+        Err(_) => Answers::default(),
+    };
+    Ok(Some(DayInput { name: "Puzzle".to_string(), example: false, answers, data, file: None, source: Some(path) }))
+}
+
+/// What `data:` holds in a `.day.ron`, the text itself or `File("path")` relative to the day asset.
+pub enum InputData {
+    Inline(String),
+    File(String),
+}
+
+impl<'de> Deserialize<'de> for InputData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(InputDataVisitor)
+    }
+}
+
+struct InputDataVisitor;
+
+impl<'de> Visitor<'de> for InputDataVisitor {
+    type Value = InputData;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string or File(\"path\")")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<InputData, E> {
+        Ok(InputData::Inline(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<InputData, E> {
+        Ok(InputData::Inline(value))
+    }
+
+    // ron hands `File("path")` over as a tuple so the name never makes it here
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<InputData, A::Error> {
+        let path = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+        Ok(InputData::File(path))
+    }
+}
+
+/// Because the name is lost `InputData` would take `("path")` or any other name too,
+/// so every `data:` in the text of a day is checked to start with a string or `File(`.
+pub fn check_input_data(text: &str) -> Result<(), String> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let mut ident = "";
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i = skip_string(bytes, i);
+                ident = "";
+            },
+            b'r' if matches!(bytes.get(i + 1), Some(b'"' | b'#')) => {
+                i = skip_raw_string(bytes, i);
+                ident = "";
+            },
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {i += 1;}
+            },
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = text[i + 2..].find("*/").map_or(bytes.len(), |end| i + end + 4);
+            },
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                let start = i;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {i += 1;}
+                ident = &text[start..i];
+            },
+            b':' if ident == "data" => {
+                let value = text[i + 1..].trim_start();
+                let is_string = value.starts_with('"') || (value.starts_with('r') && matches!(value.as_bytes().get(1), Some(b'"' | b'#')));
+                let is_file = value.strip_prefix("File").map_or(false, |rest| rest.trim_start().starts_with('('));
+                if !is_string && !is_file {
+                    let line = text[..i].lines().count();
+                    return Err(format!("data on line {} has to be a string or File(\"path\")", line));
+                }
+                i += 1;
+                ident = "";
+            },
+            b if b.is_ascii_whitespace() => i += 1,
+            _ => {
+                i += 1;
+                ident = "";
+            },
+        }
+    }
+    Ok(())
+}
+
+/// `i` is the opening quote, returns the index after the closing one.
+fn skip_string(bytes: &[u8], mut i: usize) -> usize {
+    i += 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// `i` is the `r` of `r#"..."#`, returns the index after the closing hashes.
+fn skip_raw_string(bytes: &[u8], mut i: usize) -> usize {
+    i += 1;
+    let hashes = bytes[i..].iter().take_while(|b| **b == b'#').count();
+    i += hashes + 1;
+    while i < bytes.len() {
+        if bytes[i] == b'"' && bytes[i + 1..].iter().take(hashes).filter(|b| **b == b'#').count() == hashes {
+            return i + 1 + hashes;
+        }
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::Day;
+
+    fn day(data: &str) -> Result<Day, String> {
+        Day::from_ron(&format!("(tital: \"Test\", inputs: [(name: \"Example\", data: {})])", data))
+    }
+
+    #[test]
+    fn data() {
+        assert_eq!(day("\"1\\n2\"").unwrap().inputs[0].data, "1\n2");
+        assert_eq!(day("r#\"a \"data: x\" b\"#").unwrap().inputs[0].data, "a \"data: x\" b");
+        assert_eq!(day("\"\"").unwrap().inputs[0].data, "");
+        assert_eq!(day("File(\"day1.txt\")").unwrap().inputs[0].file.as_deref(), Some("day1.txt"));
+        assert!(day("(\"day1.txt\")").is_err());
+        assert!(day("Flie(\"day1.txt\")").is_err());
+        assert!(day("File(\"a\", \"b\")").is_err());
+        assert!(day("File()").is_err());
+        assert!(day("1").is_err());
+        assert!(Day::from_ron("(tital: \"Test\", inputs: [(name: \"Example\")])").is_err());
+    }
+}
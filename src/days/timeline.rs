@@ -0,0 +1,312 @@
+use bevy::{prelude::*, ecs::system::{SystemParam, SystemParamItem, StaticSystemParam}};
+
+use crate::{Screen, advent_calendar::{AdventData, CalendarAssets}};
+
+use super::DayItem;
+
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(advance);
+        app.add_system(spawn_controls);
+        app.add_system(timeline_buttons);
+        app.add_system(scrub);
+        app.add_system(update_controls);
+    }
+}
+
+/// How many steps a second are played at a speed of 1.
+const STEPS_PER_SECOND: f32 = 5.;
+const MIN_SPEED: f32 = 0.25;
+const MAX_SPEED: f32 = 64.;
+
+/// Which step of a `Recording` is being shown, the controls at the bottom of a day drive it.
+#[derive(Resource)]
+pub struct Timeline {
+    step: usize,
+    len: usize,
+    pub playing: bool,
+    pub speed: f32,
+    /// Part of a step played since the last one.
+    progress: f32,
+}
+
+impl Timeline {
+    pub fn new(len: usize) -> Timeline {
+        Timeline { step: 0, len, playing: true, speed: 1., progress: 0. }
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.step + 1 >= self.len
+    }
+
+    pub fn toggle(&mut self) {
+        if self.at_end() && !self.playing {
+            self.seek(0);
+        }
+        self.playing = !self.playing;
+    }
+
+    pub fn next(&mut self) {
+        self.playing = false;
+        self.seek(self.step + 1);
+    }
+
+    pub fn previous(&mut self) {
+        self.playing = false;
+        self.seek(self.step.saturating_sub(1));
+    }
+
+    pub fn seek(&mut self, step: usize) {
+        self.step = step.min(self.len.saturating_sub(1));
+        self.progress = 0.;
+    }
+
+    pub fn faster(&mut self) {
+        self.speed = (self.speed * 2.).min(MAX_SPEED);
+    }
+
+    pub fn slower(&mut self) {
+        self.speed = (self.speed / 2.).max(MIN_SPEED);
+    }
+}
+
+/// The frames a solver recorded for the `Timeline` to play through.
+#[derive(Resource)]
+pub struct Recording<S>(pub Vec<S>);
+
+/// A frame of a recording, `draw` is called with every frame up to the one being shown whenever the timeline moves.
+/// Anything it spawns needs a `Frame` and a `DayItem` so it is cleared for the next frame and when the day is left.
+pub trait Playback: Sized + Send + Sync + 'static {
+    type Param: SystemParam + 'static;
+
+    fn draw(frames: &[Self], commands: &mut Commands, param: SystemParamItem<Self::Param>);
+}
+
+#[derive(Component)]
+pub struct Frame;
+
+/// Starts playing `frames` from the first one, used from a days `Visualise::visualise`.
+pub fn play<S: Playback>(commands: &mut Commands, frames: Vec<S>) {
+    commands.insert_resource(Timeline::new(frames.len()));
+    commands.insert_resource(Recording(frames));
+}
+
+/// Used from a days `Visualise::build` to draw its frames while the day is open.
+pub fn add_playback<S: Playback>(app: &mut App, year: i32, day: u8) {
+    app.add_system_set(SystemSet::on_update(Screen::Day(year, day))
+    .with_system(draw_frame::<S>))
+    .add_system_set(SystemSet::on_exit(Screen::Day(year, day))
+    .with_system(stop::<S>));
+}
+
+fn stop<S: Playback>(mut commands: Commands) {
+    commands.remove_resource::<Timeline>();
+    commands.remove_resource::<Recording<S>>();
+}
+
+fn draw_frame<S: Playback>(
+    mut commands: Commands,
+    timeline: Option<Res<Timeline>>,
+    recording: Option<Res<Recording<S>>>,
+    frames: Query<Entity, With<Frame>>,
+    param: StaticSystemParam<S::Param>,
+    mut drawn: Local<Option<usize>>,
+) {
+    let (Some(timeline), Some(recording)) = (timeline, recording) else {return;};
+    if recording.0.is_empty() {return;}
+    let step = timeline.step.min(recording.0.len() - 1);
+    // the play and speed buttons change the timeline without moving it
+    if !recording.is_changed() && *drawn == Some(step) {return;}
+    *drawn = Some(step);
+    for entity in &frames {
+        commands.entity(entity).despawn_recursive();
+    }
+    S::draw(&recording.0[..=step], &mut commands, param.into_inner());
+}
+
+fn advance(
+    timeline: Option<ResMut<Timeline>>,
+    time: Res<Time>,
+) {
+    let Some(mut timeline) = timeline else {return;};
+    if !timeline.playing {return;}
+    if timeline.at_end() {
+        timeline.playing = false;
+        return;
+    }
+    let progress = timeline.progress + time.delta_seconds() * timeline.speed * STEPS_PER_SECOND;
+    if progress < 1. {
+        // only touch the timeline when it moves so frames are not redrawn every tick
+        timeline.bypass_change_detection().progress = progress;
+        return;
+    }
+    let step = timeline.step + progress as usize;
+    timeline.seek(step);
+}
+
+#[derive(Component, Clone, Copy)]
+enum TimelineButton {
+    Start,
+    Previous,
+    Play,
+    Next,
+    End,
+    Slower,
+    Faster,
+}
+
+#[derive(Component)]
+struct PlayText;
+
+#[derive(Component)]
+struct StepText;
+
+#[derive(Component)]
+struct ScrubBar;
+
+#[derive(Component)]
+struct ScrubFill;
+
+fn spawn_controls(
+    mut commands: Commands,
+    timeline: Option<ResMut<Timeline>>,
+    assets: Res<CalendarAssets>,
+    advent_data: Res<AdventData>,
+) {
+    let Some(mut timeline) = timeline else {return;};
+    if !timeline.is_added() {return;}
+    timeline.speed = advent_data.profile().settings.playback_speed.clamp(MIN_SPEED, MAX_SPEED);
+    let style = TextStyle { font: assets.text_font.clone(), font_size: 20., color: Color::BLACK };
+    commands.spawn((NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Percent(20.), Val::Auto, Val::Auto, Val::Px(5.)),
+            size: Size::new(Val::Percent(60.), Val::Auto),
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(5.)),
+            ..Default::default()
+        },
+        background_color: Color::rgba(1., 1., 1., 0.8).into(),
+        z_index: ZIndex::Global(1),
+        ..Default::default()
+    }, DayItem)).with_children(|p| {
+        for (button, label) in [(TimelineButton::Start, "|<"), (TimelineButton::Previous, "<"), (TimelineButton::Play, "Pause"), (TimelineButton::Next, ">"), (TimelineButton::End, ">|")] {
+            p.spawn((ButtonBundle {
+                style: Style {
+                    padding: UiRect::horizontal(Val::Px(5.)),
+                    margin: UiRect::right(Val::Px(5.)),
+                    ..Default::default()
+                },
+                background_color: Color::GRAY.into(),
+                ..Default::default()
+            }, button)).with_children(|p| {
+                let mut text = p.spawn(TextBundle::from_section(label, style.clone()));
+                if let TimelineButton::Play = button {
+                    text.insert(PlayText);
+                }
+            });
+        }
+        p.spawn((NodeBundle {
+            style: Style {
+                flex_grow: 1.,
+                size: Size::new(Val::Auto, Val::Px(20.)),
+                margin: UiRect::right(Val::Px(5.)),
+                ..Default::default()
+            },
+            background_color: Color::DARK_GRAY.into(),
+            ..Default::default()
+        }, Interaction::default(), ScrubBar)).with_children(|p| {
+            p.spawn((NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(0.), Val::Percent(100.)),
+                    ..Default::default()
+                },
+                background_color: Color::GOLD.into(),
+                ..Default::default()
+            }, ScrubFill));
+        });
+        p.spawn((TextBundle::from_section("", style.clone()), StepText));
+        for (button, label) in [(TimelineButton::Slower, "-"), (TimelineButton::Faster, "+")] {
+            p.spawn((ButtonBundle {
+                style: Style {
+                    padding: UiRect::horizontal(Val::Px(5.)),
+                    margin: UiRect::left(Val::Px(5.)),
+                    ..Default::default()
+                },
+                background_color: Color::GRAY.into(),
+                ..Default::default()
+            }, button)).with_children(|p| {
+                p.spawn(TextBundle::from_section(label, style.clone()));
+            });
+        }
+    });
+}
+
+fn timeline_buttons(
+    query: Query<(&Interaction, &TimelineButton), Changed<Interaction>>,
+    timeline: Option<ResMut<Timeline>>,
+    mut advent_data: ResMut<AdventData>,
+) {
+    let Some(mut timeline) = timeline else {return;};
+    for (interaction, button) in &query {
+        if *interaction != Interaction::Clicked {continue;}
+        match button {
+            TimelineButton::Start => {timeline.playing = false; timeline.seek(0);},
+            TimelineButton::Previous => timeline.previous(),
+            TimelineButton::Play => timeline.toggle(),
+            TimelineButton::Next => timeline.next(),
+            TimelineButton::End => {timeline.playing = false; let end = timeline.len; timeline.seek(end);},
+            TimelineButton::Slower => timeline.slower(),
+            TimelineButton::Faster => timeline.faster(),
+        }
+        if let TimelineButton::Slower | TimelineButton::Faster = button {
+            // the profile starts the next recording at this speed
+            advent_data.profile_mut().settings.playback_speed = timeline.speed;
+        }
+    }
+}
+
+/// Clicking or dragging on the bar jumps to that part of the recording.
+fn scrub(
+    bars: Query<(&Interaction, &Node, &GlobalTransform), With<ScrubBar>>,
+    windows: Res<Windows>,
+    timeline: Option<ResMut<Timeline>>,
+) {
+    let Some(mut timeline) = timeline else {return;};
+    let Some(cursor) = windows.get_primary().and_then(|window| window.cursor_position()) else {return;};
+    for (interaction, node, transform) in &bars {
+        if *interaction != Interaction::Clicked || node.size().x <= 0. {continue;}
+        let left = transform.translation().x - node.size().x / 2.;
+        let fraction = ((cursor.x - left) / node.size().x).clamp(0., 1.);
+        let step = (fraction * timeline.len.saturating_sub(1) as f32).round() as usize;
+        if step != timeline.step {
+            timeline.playing = false;
+            timeline.seek(step);
+        }
+    }
+}
+
+fn update_controls(
+    timeline: Option<Res<Timeline>>,
+    mut play: Query<&mut Text, (With<PlayText>, Without<StepText>)>,
+    mut steps: Query<&mut Text, (With<StepText>, Without<PlayText>)>,
+    mut fill: Query<&mut Style, With<ScrubFill>>,
+) {
+    let Some(timeline) = timeline else {return;};
+    if !timeline.is_changed() {return;}
+    for mut text in &mut play {
+        text.sections[0].value = if timeline.playing {"Pause"} else {"Play"}.to_string();
+    }
+    for mut text in &mut steps {
+        text.sections[0].value = format!("{}/{} x{}", timeline.step + 1, timeline.len, timeline.speed);
+    }
+    for mut style in &mut fill {
+        style.size.width = Val::Percent(if timeline.len > 1 {timeline.step as f32 / (timeline.len - 1) as f32 * 100.} else {100.});
+    }
+}
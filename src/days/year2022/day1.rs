@@ -0,0 +1,215 @@
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::{SRes, SResMut}}};
+use indexmap::IndexMap;
+
+use crate::{advent_calendar::AdventData, elf::{ElfParts, Elf, ElfPart}, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise};
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 1, CalorieList, Visualiser>("Calorie Counting");
+
+pub(super) struct Visualiser;
+
+#[derive(Resource)]
+pub(super) struct Rations(Vec<Handle<Image>>);
+impl FromWorld for Rations {
+    fn from_world(world: &mut World) -> Self {
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+        let asset_server = world.resource::<AssetServer>();
+        let advent_data = world.resource::<AdventData>();
+        let mut rations: Vec<Handle<Image>> = asset_server.load_folder("rations").unwrap().into_iter().map(|r| r.typed()).collect();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed());
+        rations.shuffle(&mut rng);
+        Rations(rations)
+    }
+}
+
+#[derive(Resource)]
+struct ElfData {
+    elfs: IndexMap<Handle<Elf>, ElfRations>,
+    min: usize,
+    max: usize,
+}
+
+impl Default for ElfData {
+    fn default() -> Self {
+        ElfData { elfs: IndexMap::default(), min: usize::MAX, max: 0 }
+    }
+}
+
+impl ElfData {
+    fn add(&mut self, elf: ElfRations, id: Handle<Elf>) {
+        for r in elf.rations.iter() {
+            self.min = (*r).min(self.min);
+            self.max = (*r).max(self.max);
+        }
+        self.elfs.insert(id, elf);
+    }
+}
+
+#[derive(Default)]
+struct ElfRations {
+    rations: Vec<usize>,
+    total: usize,
+}
+
+impl ElfRations {
+    fn add(&mut self, ration: usize) {
+        self.rations.push(ration);
+        self.total += ration;
+    }
+}
+
+pub(crate) struct CalorieList {
+    elfs: Vec<ElfRations>,
+}
+
+impl CalorieList {
+    fn top(&self, n: usize) -> usize {
+        let mut totals: Vec<usize> = self.elfs.iter().map(|e| e.total).collect();
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+        totals.into_iter().take(n).sum()
+    }
+}
+
+impl Puzzle for CalorieList {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut elfs = Vec::new();
+        let mut current_elf = ElfRations::default();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                if !current_elf.rations.is_empty() {
+                    elfs.push(std::mem::take(&mut current_elf));
+                }
+                continue;
+            }
+            let ration = line.parse().map_err(|e| ParseError::at(INFO.number, input, line, format!("{:?} is not a number of calories: {}", line, e)))?;
+            current_elf.add(ration);
+        }
+        if !current_elf.rations.is_empty() {
+            elfs.push(current_elf);
+        }
+        Ok(CalorieList { elfs })
+    }
+
+    fn part1(&self) -> usize {
+        self.top(1)
+    }
+
+    fn part2(&self) -> usize {
+        self.top(3)
+    }
+}
+
+impl Visualise<CalorieList> for Visualiser {
+    type Param = (SResMut<Assets<Elf>>, SRes<ElfParts>, SRes<AdventData>, SRes<Rations>, SRes<Assets<ElfPart>>);
+
+    fn build(app: &mut App) {
+        crate::advent_calendar::init_seeded::<Rations>(app);
+    }
+
+    fn visualise(calories: CalorieList, commands: &mut Commands, (mut asset_elf, elf_parts, advent_data, rations, asset_parts): SystemParamItem<Self::Param>) {
+        let mut elf_data = ElfData::default();
+        let mut seed = advent_data.rng_seed();
+        for elf in calories.elfs {
+            elf_data.add(elf, asset_elf.add(elf_parts.random_elf(seed)));
+            seed += 1;
+        }
+        let mut children = vec![];
+        // rations all under the number of images would give a range of 0
+        let range = (elf_data.max.saturating_sub(elf_data.min) / rations.0.len().max(1)).max(1);
+        let mut most = 0;
+        let mut most2 = 0;
+        let mut most3 = 0;
+        for (elf_handle, elf_rations) in elf_data.elfs.iter() {
+            if elf_rations.total >= most3 {
+                if elf_rations.total >= most2 {
+                    most3 = most2;
+                    if elf_rations.total >= most {
+                        most2 = most;
+                        most = elf_rations.total;
+                    }
+                    else {
+                        most2 = elf_rations.total;
+                    }
+                } else {
+                    most3 = elf_rations.total;
+                }
+
+            let elf = asset_elf.get(elf_handle).unwrap();
+            let elf_id = elf.draw(asset_parts.as_ref(), commands, 0.2).unwrap();
+            let child = commands
+            .spawn((NodeBundle{
+                style: Style {
+                    justify_content: JustifyContent::FlexStart,
+                    size: Size::new(Val::Percent(100.), Val::Px(40.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }, elf_handle.clone()))
+            .add_child(elf_id).with_children(|p| {
+                for ration in elf_rations.rations.iter() {
+                    let ration_index = (ration - elf_data.min) / range;
+                    p.spawn(ImageBundle {
+                        image: rations.0.get(ration_index % rations.0.len().max(1)).cloned().unwrap_or_default().into(),
+                        style: Style {
+                            size: Size::new(Val::Px(25.), Val::Px(25.)),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    });
+                }
+            }).id();
+            children.push(child);
+            }
+        }
+        commands.spawn((NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.), Val::Percent(100.)),
+                position_type: PositionType::Absolute,
+                position: UiRect::top(Val::Px(150.)),
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            ..Default::default()
+        }, DayItem)).push_children(&children);
+        commands.insert_resource(elf_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn example() {
+        let list = CalorieList::parse(EXAMPLE).unwrap();
+        assert_eq!(list.part1(), 24000);
+        assert_eq!(list.part2(), 45000);
+    }
+
+    #[test]
+    fn errors() {
+        let error = CalorieList::parse("1000\n\n2x00").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,286 @@
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::SRes}};
+
+use crate::{advent_calendar::CalendarAssets, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise};
+
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 2, EncryptedGuide, Visualiser>("Rock Paper Scissors");
+
+pub(super) struct Visualiser;
+
+#[derive(Default)]
+struct StrategyGuide{
+    moves: Vec<(Move, Move)>,
+    total_score: usize,
+}
+
+#[derive(Resource)]
+pub(super) struct Hands {
+    rock: Handle<Image>,
+    paper: Handle<Image>,
+    scissors: Handle<Image>,
+}
+
+impl Hands {
+    fn get_hand(&self, hand: &Move) -> Handle<Image> {
+        match hand {
+            Move::Rock => self.rock.clone(),
+            Move::Paper => self.paper.clone(),
+            Move::Scissors => self.scissors.clone(),
+        }
+    }
+}
+
+impl FromWorld for Hands {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Hands { rock: asset_server.load("rock.png"), paper: asset_server.load("paper.png"), scissors: asset_server.load("scissors.png") }
+    }
+}
+
+impl StrategyGuide {
+    fn play_move(&mut self, there_move: Move, your_move: Move) {
+        let outcome = your_move.vs(&there_move);
+        self.total_score += outcome as usize + your_move as usize;
+        self.moves.push((there_move, your_move));
+    }
+}
+
+pub(crate) struct EncryptedGuide {
+    rounds: Vec<(Move, Move, OutCome)>,
+}
+
+impl EncryptedGuide {
+    fn play(&self, strategy: impl Fn(&(Move, Move, OutCome)) -> Move) -> StrategyGuide {
+        let mut game = StrategyGuide::default();
+        for round in self.rounds.iter() {
+            game.play_move(round.0, strategy(round));
+        }
+        game
+    }
+    fn strategy_one(&self) -> StrategyGuide {
+        self.play(|(_, your_move, _)| *your_move)
+    }
+    fn strategy_two(&self) -> StrategyGuide {
+        self.play(|(there_move, _, outcome)| outcome.agains(there_move))
+    }
+    fn perfect(&self) -> StrategyGuide {
+        self.play(|(there_move, _, _)| OutCome::Win.agains(there_move))
+    }
+}
+
+impl Puzzle for EncryptedGuide {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut rounds = Vec::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {continue;}
+            let there_move = match line.chars().next() {
+                Some('A') => {Move::Rock},
+                Some('B') => {Move::Paper},
+                Some('C') => {Move::Scissors},
+                a => {return Err(ParseError::at(INFO.number, input, line, format!("{:?} is not A, B or C", a)));}
+            };
+            let (last, a) = line.char_indices().last().unwrap_or((0, ' '));
+            let (your_move, outcome) = match a {
+                'X' => {(Move::Rock, OutCome::Loss)},
+                'Y' => {(Move::Paper, OutCome::Draw)},
+                'Z' => {(Move::Scissors, OutCome::Win)},
+                a => {return Err(ParseError::at(INFO.number, input, &line[last..], format!("{:?} is not X, Y or Z", a)));}
+            };
+            rounds.push((there_move, your_move, outcome));
+        }
+        Ok(EncryptedGuide { rounds })
+    }
+
+    fn part1(&self) -> usize {
+        self.strategy_one().total_score
+    }
+
+    fn part2(&self) -> usize {
+        self.strategy_two().total_score
+    }
+}
+
+impl Visualise<EncryptedGuide> for Visualiser {
+    type Param = (SRes<Hands>, SRes<CalendarAssets>);
+
+    fn build(app: &mut App) {
+        app.init_resource::<Hands>();
+    }
+
+    fn visualise(guide: EncryptedGuide, commands: &mut Commands, (hands, calender_assets): SystemParamItem<Self::Param>) {
+        let game0 = guide.strategy_one();
+        let game1 = guide.strategy_two();
+        let perfect_game = guide.perfect();
+        info!("Perfect Score = {} over {} plays", perfect_game.total_score, perfect_game.moves.len());
+        let games_skip = (perfect_game.moves.len() / 100).max(1);
+        let mut there_moves = vec![];
+        let mut children_g0 = vec![];
+        let mut children_g1 = vec![];
+        let mut children_pg = vec![];
+        // let mut gc = 0;
+        const HANDSIZE: f32 = 50.;
+        const FONT_SIZE: f32 = 20.;
+        for (i,((there_move, g0),((_, g1),(_, pg)))) in game0.moves.iter().zip(game1.moves.iter().zip(perfect_game.moves.iter())).enumerate().step_by(games_skip) {
+            let hand_style = Style {
+                    size: Size::new(Val::Px(HANDSIZE), Val::Px(HANDSIZE)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                };
+            there_moves.push(commands.spawn(ImageBundle {
+                image: hands.get_hand(there_move).into(),
+                style: hand_style.clone(),
+                ..Default::default()
+            }).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection{
+                        value: i.to_string(),
+                        style: TextStyle { font: calender_assets.text_font.clone(), font_size: FONT_SIZE, color: Color::PURPLE }
+                    }], alignment: TextAlignment::CENTER },
+                    ..Default::default()
+                });
+            }).id());
+            children_g0.push(commands.spawn(ImageBundle {
+                image: hands.get_hand(g0).into(),
+                style: hand_style.clone(),
+                ..Default::default()
+            }).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection{
+                        value: i.to_string(),
+                        style: TextStyle { font: calender_assets.text_font.clone(), font_size: FONT_SIZE, color: Color::MIDNIGHT_BLUE }
+                    }], alignment: TextAlignment::CENTER },
+                    ..Default::default()
+                });
+            }).id());
+            children_g1.push(commands.spawn(ImageBundle {
+                image: hands.get_hand(g1).into(),
+                style: hand_style.clone(),
+                ..Default::default()
+            }).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection{
+                        value: i.to_string(),
+                        style: TextStyle { font: calender_assets.text_font.clone(), font_size: FONT_SIZE, color: Color::GOLD }
+                    }], alignment: TextAlignment::CENTER },
+                    ..Default::default()
+                });
+            }).id());
+            children_pg.push(commands.spawn(ImageBundle {
+                image: hands.get_hand(pg).into(),
+                style: hand_style,
+                ..Default::default()
+            }).with_children(|p| {
+                p.spawn(TextBundle {
+                    text: Text { sections: vec![TextSection{
+                        value: i.to_string(),
+                        style: TextStyle { font: calender_assets.text_font.clone(), font_size: FONT_SIZE, color: Color::DARK_GREEN }
+                    }], alignment: TextAlignment::CENTER },
+                    ..Default::default()
+                });
+            }).id());
+        }
+        let mut style = Style {
+            size: Size::new(Val::Percent(25.), Val::Percent(85.)),
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Percent(0.), Val::Auto, Val::Px(150.), Val::Auto),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            flex_wrap: FlexWrap::Wrap,
+            ..Default::default()
+        };
+        commands.spawn((NodeBundle {
+            style: style.clone(),
+            ..Default::default()
+        }, DayItem)).push_children(&there_moves);
+        style.position.left = Val::Percent(25.);
+        commands.spawn((NodeBundle {
+            style: style.clone(),
+            ..Default::default()
+        }, DayItem)).push_children(&children_g0);
+        style.position.left = Val::Percent(50.);
+        commands.spawn((NodeBundle {
+            style: style.clone(),
+            ..Default::default()
+        }, DayItem)).push_children(&children_g1);
+        style.position.left = Val::Percent(75.);
+        commands.spawn((NodeBundle {
+            style,
+            ..Default::default()
+        }, DayItem)).push_children(&children_pg);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Move {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl Move {
+    fn vs(&self, other: &Move) -> OutCome {
+        use OutCome::*;
+        match (self, other) {
+            (Move::Rock, Move::Rock) => Draw,
+            (Move::Rock, Move::Paper) => Loss,
+            (Move::Rock, Move::Scissors) => Win,
+            (Move::Paper, Move::Rock) => Win,
+            (Move::Paper, Move::Paper) => Draw,
+            (Move::Paper, Move::Scissors) => Loss,
+            (Move::Scissors, Move::Rock) => Loss,
+            (Move::Scissors, Move::Paper) => Win,
+            (Move::Scissors, Move::Scissors) => Draw,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum OutCome {
+    Win = 6,
+    Draw = 3,
+    Loss = 0,
+}
+
+impl OutCome {
+    fn agains(&self, other: &Move) -> Move {
+        use OutCome::*;
+        use Move::*;
+        match (self, other) {
+            (Win, Rock) => Paper,
+            (Win, Paper) => Scissors,
+            (Win, Scissors) => Rock,
+            (Draw, Rock) => Rock,
+            (Draw, Paper) => Paper,
+            (Draw, Scissors) => Scissors,
+            (Loss, Rock) => Scissors,
+            (Loss, Paper) => Rock,
+            (Loss, Scissors) => Paper,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let guide = EncryptedGuide::parse("A Y\nB X\nC Z").unwrap();
+        assert_eq!(guide.part1(), 15);
+        assert_eq!(guide.part2(), 12);
+    }
+
+    #[test]
+    fn errors() {
+        let error = EncryptedGuide::parse("A Y\nD X").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = EncryptedGuide::parse("A Q").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
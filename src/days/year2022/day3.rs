@@ -0,0 +1,202 @@
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::SRes}};
+use rand::{SeedableRng, Rng};
+
+use crate::{advent_calendar::AdventData, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise};
+
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 3, Rucksacks, Visualiser>("Rucksack Reorganization");
+
+pub(super) struct Visualiser;
+
+#[derive(Resource)]
+pub(super) struct Items(Vec<Handle<Image>>);
+
+impl FromWorld for Items {
+    fn from_world(world: &mut World) -> Self {
+        use rand::seq::SliceRandom;
+        let advent_data = world.resource::<AdventData>();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed());
+        let assert_server = world.resource::<AssetServer>();
+        let mut items: Vec<Handle<Image>> = assert_server.load_folder("items").unwrap().into_iter().map(|f| f.typed()).collect();
+        items.shuffle(&mut rng);
+        Items(items)
+    }
+}
+
+struct Bag {
+    pocket_one: u64,
+    pocket_two: u64,
+}
+
+#[derive(Debug)]
+enum Pocket {
+    PocketOne,
+    PocketTwo,
+}
+
+impl Bag {
+    fn add_item(&mut self, pocket: Pocket, item: char) -> Result<(), char> {
+        if item < 'A' || item > 'z' || item < 'a' && item > 'Z' {
+            error!("item {} can not go in pocket", item);
+            return Ok(());
+        }
+        let item_type = if item <= 'Z' {
+            item as u8 - b'A' + 26
+        } else {
+            item as u8 - b'a'
+        };
+        let id = 1 << item_type;
+        match pocket {
+            Pocket::PocketOne => {self.pocket_one |= id},
+            Pocket::PocketTwo => {self.pocket_two |= id}
+        }
+        if self.pocket_one & self.pocket_two != 0 {
+            self.pocket_two ^= id;
+            Err(item)
+        } else {
+            Ok(())
+        }
+    }
+    fn new() -> Bag {
+        Bag { pocket_one: 0, pocket_two: 0 }
+    }
+    fn content(&self) -> u64 {
+        self.pocket_one | self.pocket_two
+    }
+}
+
+fn priority(item: char) -> usize {
+    if item <= 'Z' {
+        (item as u8 - b'A' + 27) as usize
+    } else {
+        (item as u8 - b'a' + 1) as usize
+    }
+}
+
+pub(crate) struct Rucksacks {
+    bags: Vec<Bag>,
+    dups: Vec<char>,
+}
+
+impl Puzzle for Rucksacks {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut dups = Vec::new();
+        let mut bags = Vec::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {continue;}
+            if let Some((i, char)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(INFO.number, input, &line[i..], format!("{:?} is not an item", char)));
+            }
+            if line.len() % 2 != 0 {
+                return Err(ParseError::at(INFO.number, input, line, format!("{} items can not be split evenly between two pockets", line.len())));
+            }
+            let half = line.len() / 2;
+            let mut backpack = Bag::new();
+            let mut dup = false;
+            for (i, char) in line.chars().enumerate() {
+                let pocket = if i < half {
+                    Pocket::PocketOne
+                } else {
+                    Pocket::PocketTwo
+                };
+                if let Err(char) = backpack.add_item(pocket, char) {
+                    if !dup {
+                        dups.push(char);
+                    }
+                    dup = true;
+                }
+            }
+            bags.push(backpack);
+        }
+        Ok(Rucksacks { bags, dups })
+    }
+
+    fn part1(&self) -> usize {
+        self.dups.iter().map(|c| priority(*c)).sum()
+    }
+
+    fn part2(&self) -> usize {
+        let mut badge_total = 0;
+        for group in self.bags.chunks_exact(3) {
+            let overlap = group[0].content() & group[1].content() & group[2].content();
+            if overlap > 0 {
+                badge_total += overlap.trailing_zeros() as usize + 1;
+            }
+        }
+        badge_total
+    }
+}
+
+impl Visualise<Rucksacks> for Visualiser {
+    type Param = (SRes<Items>, SRes<Windows>, SRes<AdventData>);
+
+    fn build(app: &mut App) {
+        crate::advent_calendar::init_seeded::<Items>(app);
+    }
+
+    fn visualise(rucksacks: Rucksacks, commands: &mut Commands, (items, windows, advent_data): SystemParamItem<Self::Param>) {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed());
+        let p_window = windows.get_primary().unwrap();
+        let width = p_window.width();
+        let height = p_window.height();
+        for char in rucksacks.dups.iter() {
+            let id = priority(*char);
+            let left = rng.gen();
+            let top = rng.gen();
+            let mut position = UiRect::all(Val::Auto);
+            if left {
+                position.left = Val::Px(rng.gen_range(0.0..width));
+            } else {
+                position.right = Val::Px(rng.gen_range(0.0..width));
+            }
+            if top {
+                position.top = Val::Px(rng.gen_range(150.0..height));
+            } else {
+                position.bottom = Val::Px(rng.gen_range(0.0..height-150.));
+            }
+            commands.spawn((ImageBundle {
+                image: items.0[id].clone().into(),
+                style: Style {
+                    size: Size::new(Val::Px(50.), Val::Px(50.)),
+                    position,
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }, DayItem));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn example() {
+        let rucksacks = Rucksacks::parse(EXAMPLE).unwrap();
+        assert_eq!(rucksacks.part1(), 157);
+        assert_eq!(rucksacks.part2(), 70);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Rucksacks::parse("abcd\nab1d").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Rucksacks::parse("abcd\nabc").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::puzzle::{Puzzle, ParseError};
+
+use super::{YEAR, DayInfo};
+
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 4, Assignments, ()>("Camp Cleanup");
+
+struct Range {
+    top: usize,
+    bottom: usize,
+}
+
+impl Range {
+    fn new(input: &str, range: &str) -> Result<Range, ParseError> {
+        let range = range.trim();
+        let (first, second) = range.split_once('-').ok_or_else(|| ParseError::at(INFO.number, input, range, format!("{:?} is not a range like 2-4", range)))?;
+        let section = |s: &str| s.parse().map_err(|_| ParseError::at(INFO.number, input, s, format!("{:?} is not a section number", s)));
+        Ok(Range { top: section(second)?, bottom: section(first)? })
+    }
+    fn contains(&self, other: &Range) -> bool {
+        other.top <= self.top && other.bottom >= self.bottom
+    }
+    fn overlap(&self, other: &Range) -> bool {
+        other.top >= self.bottom && other.bottom <= self.top
+    }
+}
+
+pub(crate) struct Assignments(Vec<(Range, Range)>);
+
+impl Puzzle for Assignments {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut pairs = Vec::new();
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {continue;}
+            let (r0, r1) = line.split_once(',').ok_or_else(|| ParseError::at(INFO.number, input, line, "expected two ranges split by a ','"))?;
+            pairs.push((Range::new(input, r0)?, Range::new(input, r1)?));
+        }
+        Ok(Assignments(pairs))
+    }
+
+    fn part1(&self) -> usize {
+        self.0.iter().filter(|(r0, r1)| r0.contains(r1) || r1.contains(r0)).count()
+    }
+
+    fn part2(&self) -> usize {
+        self.0.iter().filter(|(r0, r1)| r0.overlap(r1)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn example() {
+        let assignments = Assignments::parse(EXAMPLE).unwrap();
+        assert_eq!(assignments.part1(), 2);
+        assert_eq!(assignments.part2(), 4);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Assignments::parse("2-4,6-8\n2-3,4x5").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = Assignments::parse("2-4,6-8\n2-a,4-5").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Assignments::parse("2-4 6-8").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
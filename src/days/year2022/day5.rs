@@ -0,0 +1,277 @@
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::SRes}};
+use rand::{SeedableRng, seq::SliceRandom};
+
+use crate::{advent_calendar::{CalendarAssets, AdventData}, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise, timeline::{self, Frame, Playback}};
+
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 5, Ship, Visualiser>("Supply Stacks");
+
+pub(super) struct Visualiser;
+
+#[derive(Resource)]
+pub(super) struct Crates(Vec<Handle<Image>>);
+
+impl FromWorld for Crates {
+    fn from_world(world: &mut World) -> Self {
+        let advent_data = world.resource::<AdventData>();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed());
+        let asset_server = world.resource::<AssetServer>();
+        let mut crates: Vec<Handle<Image>> = asset_server.load_folder("crates").unwrap().into_iter().map(|f| f.typed()).collect();
+        crates.shuffle(&mut rng);
+        Crates(crates)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Ship {
+    stacks: Vec<Vec<char>>,
+    steps: Vec<Step>,
+}
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    from: usize,
+    to: usize,
+    x: usize,
+}
+impl Step {
+    fn from_str(input: &str, line: &str, stacks: usize) -> Result<Step, ParseError> {
+        let mut words = line.split(' ');
+        let (_, x) = number(input, line, &mut words, "move")?;
+        let (from_word, from) = number(input, line, &mut words, "from")?;
+        let (to_word, to) = number(input, line, &mut words, "to")?;
+        for (word, stack) in [(from_word, from), (to_word, to)] {
+            if stack == 0 || stack > stacks {
+                return Err(ParseError::at(INFO.number, input, word, format!("there is no stack {}", stack)));
+            }
+        }
+        Ok(Self { from, to, x })
+    }
+}
+
+/// Reads `keyword` and the number after it.
+fn number<'a>(input: &str, line: &'a str, words: &mut impl Iterator<Item = &'a str>, keyword: &str) -> Result<(&'a str, usize), ParseError> {
+    let end = &line[line.len()..];
+    let word = words.next().unwrap_or(end);
+    if word != keyword {
+        return Err(ParseError::at(INFO.number, input, word, format!("expected {:?}", keyword)));
+    }
+    let value = words.next().unwrap_or(end);
+    let number = value.parse().map_err(|_| ParseError::at(INFO.number, input, value, format!("{:?} is not a number", value)))?;
+    Ok((value, number))
+}
+
+impl Ship {
+    fn from_str(input: &str) -> Result<Ship, ParseError> {
+        let mut lines = input.lines().peekable();
+        let mut stacks: Vec<Vec<char>> = Vec::new();
+        while let Some(line) = lines.next_if(|l| l.contains('[')) {
+            for (i, char) in line.char_indices() {
+                if i % 4 != 1 || char == ' ' {continue;}
+                if !char.is_ascii_uppercase() {
+                    return Err(ParseError::at(INFO.number, input, &line[i..], format!("{:?} is not a crate", char)));
+                }
+                if stacks.len() <= i / 4 {
+                    stacks.resize(i / 4 + 1, Vec::new());
+                }
+                stacks[i / 4].push(char);
+            }
+        }
+        //stack id line
+        if let Some(ids) = lines.next() {
+            let count = ids.split_whitespace().count();
+            if stacks.len() < count {
+                stacks.resize(count, Vec::new());
+            }
+        }
+        for stack in stacks.iter_mut() {
+            stack.reverse();
+        }
+        let mut ship = Ship { steps: Vec::new(), stacks };
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            ship.add_step(Step::from_str(input, line, ship.stacks.len())?);
+        }
+        Ok(ship)
+    }
+
+    fn apply_steps(&mut self, single: bool) {
+        for Step{ from, to, x } in self.steps.iter() {
+            let mut from_vec = std::mem::take(&mut self.stacks[*from - 1]);
+            if single {
+                apply_step(&mut from_vec, &mut self.stacks[*to - 1], *x);
+            } else {
+                apply_muti_step(&mut from_vec, &mut self.stacks[*to - 1], *x);
+            }
+            std::mem::swap(&mut self.stacks[*from - 1], &mut from_vec);
+        }
+    }
+
+    fn add_step(&mut self, step: Step) {
+        self.steps.push(step);
+    }
+
+    /// The stacks before any moves and after each one, for the timeline to play through.
+    fn frames(&self, single: bool) -> Vec<Stacks> {
+        let mut ship = self.clone();
+        let mut frames = vec![Stacks(ship.stacks.clone())];
+        for step in self.steps.iter() {
+            ship.steps = vec![*step];
+            ship.apply_steps(single);
+            frames.push(Stacks(ship.stacks.clone()));
+        }
+        frames
+    }
+
+    fn rearranged(&self, single: bool) -> Ship {
+        let mut ship = self.clone();
+        ship.apply_steps(single);
+        ship
+    }
+
+    fn tops(&self) -> String {
+        self.stacks.iter().filter_map(|stack| stack.last()).collect()
+    }
+}
+
+impl Puzzle for Ship {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ship::from_str(input)
+    }
+
+    fn part1(&self) -> String {
+        self.rearranged(true).tops()
+    }
+
+    fn part2(&self) -> String {
+        self.rearranged(false).tops()
+    }
+}
+
+fn apply_step(from: &mut Vec<char>, to: &mut Vec<char>, x: usize) {
+    for _ in 0..x {
+        let out = from.pop();
+        if let Some(c) = out {to.push(c)}
+    }
+}
+fn apply_muti_step(from: &mut Vec<char>, to: &mut Vec<char>, x: usize) {
+    let mut stack = Vec::with_capacity(x);
+    for _ in 0..x {
+        if let Some(c) = from.pop() {stack.push(c)}
+    }
+    while let Some(c) = stack.pop() {
+        to.push(c);
+    }
+}
+
+impl Visualise<Ship> for Visualiser {
+    type Param = ();
+
+    fn build(app: &mut App) {
+        crate::advent_calendar::init_seeded::<Crates>(app);
+        timeline::add_playback::<Stacks>(app, INFO.year, INFO.number);
+    }
+
+    fn visualise(ship: Ship, commands: &mut Commands, _: SystemParamItem<Self::Param>) {
+        timeline::play(commands, ship.frames(false));
+    }
+}
+
+/// The crates on the ship after a move.
+pub(super) struct Stacks(Vec<Vec<char>>);
+
+impl Playback for Stacks {
+    type Param = (SRes<CalendarAssets>, SRes<Crates>);
+
+    fn draw(frames: &[Stacks], commands: &mut Commands, (calendar_assets, crates): SystemParamItem<Self::Param>) {
+        let Some(Stacks(stacks)) = frames.last() else {return;};
+        const STACKSIZE: f32 = 100./9.;
+        let mut stack_style = Style {
+            position: UiRect::new(Val::Percent(0.), Val::Auto, Val::Px(150.), Val::Auto),
+            size: Size::new(Val::Percent(STACKSIZE), Val::Auto),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::ColumnReverse,
+            min_size: Size::new(Val::Percent(STACKSIZE), Val::Percent(85.)),
+            ..Default::default()
+        };
+        let container_style = Style {
+            size: Size::new(Val::Percent(100.), Val::Auto),
+            aspect_ratio: Some(1.0),
+            align_content: AlignContent::Center,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..Default::default()
+        };
+        let text_style = TextStyle {
+            font_size: 50.,
+            font: calendar_assets.text_font.clone(),
+            color: Color::WHITE,
+        };
+        for (i, stack) in stacks.iter().enumerate() {
+            stack_style.position.left = Val::Percent(i as f32 * STACKSIZE);
+            commands.spawn((NodeBundle {
+                style: stack_style.clone(),
+                ..Default::default()
+            }, DayItem, Frame)).with_children(|p| {
+                for container in stack {
+                    p.spawn(ImageBundle {
+                        image: crates.0[(*container as u8 - b'A') as usize].clone().into(),
+                        style: container_style.clone(),
+                        ..Default::default()
+                    }).with_children(|p| {
+                        p.spawn(TextBundle {
+                            text: Text {
+                                sections: vec![TextSection {value: container.to_string(), style: text_style.clone()}],
+                                alignment: TextAlignment::CENTER,
+                            },
+                            style: Style {
+                                justify_content: JustifyContent::Center,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        });
+                    });
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn example() {
+        let ship = Ship::parse(EXAMPLE).unwrap();
+        assert_eq!(ship.part1(), "CMZ");
+        assert_eq!(ship.part2(), "MCD");
+    }
+
+    #[test]
+    fn errors() {
+        let error = Ship::parse("[A] [b]\n 1   2 \n\nmove 1 from 1 to 2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = Ship::parse("[A] [B]\n 1   2 \n\nmove 1 from 4 to 1").err().unwrap();
+        assert_eq!((error.line, error.column), (4, 13));
+        let error = Ship::parse("[A] [B]\n 1   2 \n\nmove x from 1 to 2").err().unwrap();
+        assert_eq!((error.line, error.column), (4, 6));
+    }
+}
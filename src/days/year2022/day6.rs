@@ -0,0 +1,145 @@
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::SRes}};
+
+use crate::{advent_calendar::CalendarAssets, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise, timeline::{self, Frame, Playback}};
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 6, Signal, Visualiser>("Tuning Trouble");
+
+pub(super) struct Visualiser;
+
+fn contains_dup(string: &str) -> bool {
+    for (i, char) in string.char_indices() {
+        if string[i+1..].contains(char) {return true;}
+    }
+    false
+}
+
+fn find_marker(data: &str, len: usize) -> Option<usize> {
+    (len..=data.len()).find(|end| !contains_dup(&data[end - len..*end]))
+}
+
+pub(crate) struct Signal {
+    data: String,
+    start_of_packet: usize,
+    start_of_message: usize,
+}
+
+impl Puzzle for Signal {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let data = input.trim();
+        if let Some((i, char)) = data.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(INFO.number, input, &data[i..], format!("{:?} is not part of the signal", char)));
+        }
+        let start_of_packet = find_marker(data, 4).ok_or_else(|| ParseError::at(INFO.number, input, data, "there is no start-of-packet marker"))?;
+        let start_of_message = find_marker(data, 14).ok_or_else(|| ParseError::at(INFO.number, input, data, "there is no start-of-message marker"))?;
+        Ok(Signal { data: data.to_string(), start_of_packet, start_of_message })
+    }
+
+    fn part1(&self) -> usize {
+        self.start_of_packet
+    }
+
+    fn part2(&self) -> usize {
+        self.start_of_message
+    }
+}
+
+impl Visualise<Signal> for Visualiser {
+    type Param = ();
+
+    fn build(app: &mut App) {
+        timeline::add_playback::<Window>(app, INFO.year, INFO.number);
+    }
+
+    fn visualise(signal: Signal, commands: &mut Commands, _: SystemParamItem<Self::Param>) {
+        let mut frames = Vec::new();
+        for (len, marker) in [(4, signal.start_of_packet), (14, signal.start_of_message)] {
+            for end in len..=marker {
+                frames.push(Window { chars: signal.data[end - len..end].to_string(), end, found: end == marker });
+            }
+        }
+        timeline::play(commands, frames);
+    }
+}
+
+/// The chars being checked for a marker, packets look at 4 and messages at 14.
+pub(super) struct Window {
+    chars: String,
+    end: usize,
+    found: bool,
+}
+
+impl Playback for Window {
+    type Param = SRes<CalendarAssets>;
+
+    fn draw(frames: &[Window], commands: &mut Commands, assets: SystemParamItem<Self::Param>) {
+        let Some(window) = frames.last() else {return;};
+        let packet = window.chars.len() == 4;
+        let color = match (packet, window.found) {
+            (_, true) => Color::GREEN,
+            (true, false) => Color::BLUE,
+            (false, false) => Color::RED,
+        };
+        commands.spawn((TextBundle {
+            text: Text { sections: vec![TextSection {
+                value: format!("{} {}: {}", if packet {"start-of-packet"} else {"start-of-message"}, if window.found {"found at"} else {"checking"}, window.end),
+                style: TextStyle { font: assets.text_font.clone(), font_size: 30., color: Color::BLACK },
+            }], alignment: TextAlignment::default() },
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Percent(40.), Val::Auto, Val::Px(110.), Val::Auto),
+                ..Default::default()
+            },
+            ..Default::default()
+        }, DayItem, Frame));
+        let mut parent = commands.spawn((NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.), Val::Percent(85.)),
+                position_type: PositionType::Absolute,
+                position: UiRect::top(Val::Px(150.)),
+                flex_direction: FlexDirection::Row,
+                ..Default::default()
+            },
+            ..Default::default()
+        }, DayItem, Frame));
+        const BARHIGHT: f32 = 100./28.;
+        const BARSIZE: Size = Size {height: Val::Percent(BARHIGHT), width: Val::Percent(100./18.)};
+        for char in window.chars.bytes() {
+            parent.with_children(|p| {
+                p.spawn(NodeBundle {
+                    background_color: color.into(),
+                    style: Style {
+                        size: BARSIZE,
+                        position: UiRect::top(Val::Percent((char - b'a') as f32 * BARHIGHT)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let signal = Signal::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(signal.part1(), 7);
+        assert_eq!(signal.part2(), 19);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Signal::parse("mjqjP").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+        let error = Signal::parse("abcabcabc").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::SRes}};
+
+use crate::{advent_calendar::CalendarAssets, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise, timeline::{self, Frame, Playback}};
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 7, FileSystem, Visualiser>("No Space Left On Device");
+
+pub(super) struct Visualiser;
+
+impl Visualise<FileSystem> for Visualiser {
+    type Param = SRes<CalendarAssets>;
+
+    fn build(app: &mut App) {
+        timeline::add_playback::<Prompt>(app, INFO.year, INFO.number);
+    }
+
+    fn visualise(fs: FileSystem, commands: &mut Commands, calender_assets: SystemParamItem<Self::Param>) {
+        let delete = fs.part2();
+        info!("delete: {} to free {}", find_name(&fs.items, delete), delete);
+        let root = commands.spawn((NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                position: UiRect::top(Val::Px(150.)),
+                size: Size::new(Val::Percent(100.), Val::Percent(85.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }, DayItem)).id();
+        draw_fs(commands, &fs.items, root, &calender_assets.text_font);
+        timeline::play(commands, fs.walk);
+    }
+}
+
+/// Lines of the terminal shown at once.
+const TERMINAL_LINES: usize = 20;
+
+impl Playback for Prompt {
+    type Param = SRes<CalendarAssets>;
+
+    fn draw(frames: &[Prompt], commands: &mut Commands, assets: SystemParamItem<Self::Param>) {
+        let Some(current) = frames.last() else {return;};
+        let style = |color: Color| TextStyle { font: assets.text_font.clone(), font_size: 20., color };
+        commands.spawn((NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Auto, Val::Px(0.), Val::Px(150.), Val::Auto),
+                size: Size::new(Val::Percent(40.), Val::Auto),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(5.)),
+                ..Default::default()
+            },
+            background_color: Color::rgba(0., 0., 0., 0.8).into(),
+            ..Default::default()
+        }, DayItem, Frame)).with_children(|p| {
+            p.spawn(TextBundle::from_section(current.path.clone(), style(Color::GOLD)));
+            for prompt in frames.iter().rev().take(TERMINAL_LINES).rev() {
+                let color = if prompt.line.starts_with('$') {Color::GREEN} else {Color::WHITE};
+                p.spawn(TextBundle::from_section(prompt.line.clone(), style(color)));
+            }
+        });
+    }
+}
+
+fn draw_fs(commands: &mut Commands, item: &Item, root: Entity, font: &Handle<Font>) {
+    if let Item::Dir(items) = item {
+        commands.entity(root).with_children( |p| {
+            for (name, val) in items.iter() {
+                match val {
+                    Item::File(size) => {
+                        p.spawn(TextBundle {
+                            text: Text { sections: vec![TextSection {
+                                value: format!("{}:{}", name, size),
+                                style: TextStyle { font: font.clone(), font_size: 10., color: Color::BLACK },
+                            }], alignment: TextAlignment::CENTER },
+                            ..Default::default()
+                        });
+                    },
+                    Item::Dir(_) => {
+                        p.spawn(ButtonBundle::default()).with_children(|p| {p.spawn(TextBundle {
+                            text: Text { sections: vec![TextSection {
+                                value: format!("{}: Dir", name),
+                                style: TextStyle { font: font.clone(), font_size: 10., color: Color::BLACK },
+                            }], alignment: TextAlignment::CENTER },
+                            ..Default::default()
+                        });});
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// The size of the smallest dir bigger than `min`, `None` when there is no dir that big.
+fn find_delete(item: &Item, min: usize) -> Option<usize> {
+    match item {
+        Item::File(_) => None,
+        Item::Dir(items) => {
+            let sub_min = items.values().filter_map(|i| find_delete(i, min)).min();
+            sub_min.or(Some(item.size()).filter(|size| *size > min))
+        }
+    }
+}
+
+fn find_name(item: &Item, size: usize) -> String {
+    match item {
+        Item::File(_) => {"File".to_string()},
+        Item::Dir(items) => {
+            for (name, item) in items.iter() {
+                if item.size() == size {return name.to_string();}
+                let sub_name = find_name(item, size);
+                if sub_name != "File" && sub_name != "Dir" {return name.to_string()};
+            }
+            return "Dir".to_string();
+        }
+    }
+}
+
+pub(crate) struct FileSystem {
+    items: Item,
+    current_path: Vec<String>,
+    /// Every line of the terminal output with the directory it was in, for the timeline to play through.
+    walk: Vec<Prompt>,
+}
+
+pub(super) struct Prompt {
+    path: String,
+    line: String,
+}
+
+impl FileSystem {
+    fn new() -> FileSystem {
+        FileSystem { items: Item::Dir(HashMap::new()), current_path: Vec::new(), walk: Vec::new() }
+    }
+    fn cd(&mut self, to: &str) {
+        if to == ".." {
+            self.current_path.pop();
+        } else if to == "/" {
+            self.current_path.clear();
+        } else {
+            self.current_path.push(to.to_string());
+        }
+    }
+    fn add_file(&mut self, name: &str, size: usize) -> Result<(), FSError> {
+        let mut dir = &mut self.items;
+        for path in self.current_path.iter() {
+            if let Item::Dir(items) = dir {
+                dir = items.get_mut(path).ok_or(FSError::NoSuchFileOrDir(path.to_string()))?;
+            } else {
+                return Err(FSError::NotDir(path.clone()));
+            }
+        }
+        if let Item::Dir(items) = dir {
+            items.insert(name.to_string(), Item::File(size));
+        }
+        Ok(())
+    }
+    fn add_dir(&mut self, name: &str) -> Result<(), FSError> {
+        let mut dir = &mut self.items;
+        for path in self.current_path.iter() {
+            if let Item::Dir(items) = dir {
+                dir = items.get_mut(path).ok_or(FSError::NoSuchFileOrDir(path.to_string()))?;
+            } else {
+                return Err(FSError::NotDir(path.clone()));
+            }
+        }
+        if let Item::Dir(items) = dir {
+            items.insert(name.to_string(), Item::Dir(HashMap::new()));
+        }
+        Ok(())
+    }
+    fn record(&mut self, line: &str) {
+        let path = format!("/{}", self.current_path.join("/"));
+        self.walk.push(Prompt { path, line: line.to_string() });
+    }
+    fn sum_small(&self) -> usize {
+        self.items.filter_dir(100000)
+    }
+}
+
+const DISK_SIZE: usize = 70000000;
+const UPDATE_SIZE: usize = 30000000;
+
+impl Puzzle for FileSystem {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let error = |part: &str, message: String| ParseError::at(INFO.number, input, part, message);
+        let mut fs = FileSystem::new();
+        let mut lines = input.lines().peekable();
+        while let Some(line) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() {continue;}
+            let mut words = line.split(' ');
+            let end = &line[line.len()..];
+            let w = words.next().unwrap_or(end);
+            if w != "$" {return Err(error(w, "commands start with $".to_string()));}
+            match words.next() {
+                Some("cd") => {
+                    let Some(path) = words.next() else {return Err(error(end, "no path after cd".to_string()));};
+                    fs.cd(path);
+                    fs.record(line);
+                },
+                Some("ls") => {
+                    fs.record(line);
+                    while let Some(line) = lines.next_if(|next| !next.starts_with('$')) {
+                        let line = line.trim();
+                        if line.is_empty() {continue;}
+                        let mut segs = line.split(' ');
+                        let end = &line[line.len()..];
+                        let first = segs.next().unwrap_or(end);
+                        let Some(name) = segs.next() else {return Err(error(end, "expected a file name".to_string()));};
+                        let added = if first == "dir" {
+                            fs.add_dir(name)
+                        } else {
+                            let size = first.parse::<usize>().map_err(|_| error(first, format!("{:?} is not dir or a file size", first)))?;
+                            fs.add_file(name, size)
+                        };
+                        added.map_err(|e| error(name, format!("{:?}", e)))?;
+                        fs.record(line);
+                    }
+                },
+                Some(command) => {return Err(error(command, format!("{:?} is not cd or ls", command)));},
+                None => {return Err(error(end, "command needs to be followed by ls or cd".to_string()));}
+            }
+        }
+        Ok(fs)
+    }
+
+    fn part1(&self) -> usize {
+        self.sum_small()
+    }
+
+    fn part2(&self) -> usize {
+        let used = self.items.size();
+        let free = DISK_SIZE.saturating_sub(used);
+        let need = UPDATE_SIZE.saturating_sub(free);
+        // deleting everything is the most that can be freed
+        find_delete(&self.items, need).unwrap_or(used)
+    }
+}
+
+#[derive(Debug)]
+enum Item {
+    Dir(HashMap<String, Item>),
+    File(usize),
+}
+
+impl Item {
+    fn size(&self) -> usize {
+        match self {
+            Item::File(size) => *size,
+            Item::Dir(items) => {
+                items.values().map(|i| i.size()).sum()
+            }
+        }
+    }
+    fn filter_dir(&self, size: usize) -> usize {
+        match self {
+            Item::File(_) => 0,
+            Item::Dir(items) => {
+                let sum: usize = items.values().map(|i| i.filter_dir(size)).sum();
+                let d_size = self.size();
+                sum + if d_size <= size {d_size} else {0}
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+enum FSError {
+    NotDir(String),
+    NoSuchFileOrDir(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let fs = FileSystem::parse(include_str!("../../../assets/2022/days/day7.example.txt")).unwrap();
+        assert_eq!(fs.part1(), 95437);
+        assert_eq!(fs.part2(), 24933642);
+    }
+
+    #[test]
+    fn bigger_than_the_disk() {
+        let fs = FileSystem::parse("$ cd /\n$ ls\n80000000 big").unwrap();
+        assert_eq!(fs.part2(), 80000000);
+    }
+
+    #[test]
+    fn errors() {
+        let error = FileSystem::parse("$ cd /\n$ dir a").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = FileSystem::parse("$ cd /\n$ ls\nbig a").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use bevy::{prelude::*, ecs::system::{SystemParamItem, lifetimeless::SRes}};
+use rand::{SeedableRng, seq::SliceRandom};
+
+use crate::{advent_calendar::AdventData, puzzle::{Puzzle, ParseError}};
+
+use super::{YEAR, DayInfo, DayItem, Visualise};
+
+pub(super) const INFO: DayInfo = DayInfo::new::<YEAR, 8, Forest, Visualiser>("Treetop Tree House");
+
+pub(super) struct Visualiser;
+
+#[derive(Resource)]
+pub(super) struct Trees(Vec<Handle<Image>>);
+
+impl FromWorld for Trees {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        let seed = world.resource::<AdventData>();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed.rng_seed());
+        let mut handle: Vec<Handle<Image>> = asset_server.load_folder("trees").unwrap().into_iter().map(|f| f.typed()).collect();
+        handle.shuffle(&mut rng);
+        Trees(handle)
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+struct Tree {
+    x: usize,
+    y: usize,
+}
+
+impl Tree {
+    fn new(x: usize, y: usize) -> Tree {
+        Tree { x, y }
+    }
+}
+
+pub(crate) struct Forest {
+    width: usize,
+    hight: usize,
+    trees: HashMap<Tree, u8>
+}
+
+impl Forest {
+    fn new() -> Forest {
+        Forest { width: 0, hight: 0, trees: HashMap::default() }
+    }
+    fn is_visible(&self, tree: &Tree) -> bool {
+        let hight = self.trees.get(tree).unwrap();
+        if tree.x == 0 || tree.y == 0 || tree.x == self.width - 1 || tree.y == self.hight - 1 {return true;}
+        let mut vis_x = true;
+        let mut vis_xn = true;
+        let mut vis_y = true;
+        let mut vis_yn = true;
+        for x in 0..tree.x {
+            if self.trees.get(&Tree { x, y: tree.y }).unwrap() >= hight {
+                vis_x = false;
+            }
+        }
+        for x in (tree.x..self.width).skip(1) {
+            if self.trees.get(&Tree { x, y: tree.y }).unwrap() >= hight {
+                vis_xn = false;
+            }
+        }
+        for y in 0..tree.y {
+            if self.trees.get(&Tree { x: tree.x, y}).unwrap() >= hight {vis_y = false;}
+        }
+        for y in (tree.y..self.hight).skip(1) {
+            if self.trees.get(&Tree { x: tree.x, y}).unwrap() >= hight {vis_yn = false;}
+        }
+        vis_x || vis_xn || vis_y || vis_yn
+    }
+    fn scenic(&self, tree: &Tree) -> usize {
+        if tree.x == 0 || tree.y == 0 || tree.x == self.width - 1 || tree.y == self.hight - 1 {return 0;}
+        let hight = self.trees.get(tree).unwrap();
+        let mut vis_x = self.width - tree.x - 1;
+        let mut vis_xn = tree.x;
+        let mut vis_y = self.hight - tree.y - 1;
+        let mut vis_yn = tree.y;
+        for (i, x) in (0..tree.x).enumerate().skip(1) {
+            let x = tree.x - x;
+            if self.trees.get(&Tree { x, y: tree.y }).unwrap() >= hight {
+                vis_xn = i;
+                break;
+            }
+        }
+        for (i, x) in (tree.x..self.width).enumerate().skip(1) {
+            if self.trees.get(&Tree { x, y: tree.y }).unwrap() >= hight {
+                vis_x = i;
+                break;
+            }
+        }
+        for (i, y) in (0..tree.y).enumerate().skip(1) {
+            let y = tree.y - y;
+            if self.trees.get(&Tree { x: tree.x, y}).unwrap() >= hight {
+                vis_yn = i; break;}
+        }
+        for (i, y) in (tree.y..self.hight).enumerate().skip(1) {
+            if self.trees.get(&Tree { x: tree.x, y}).unwrap() >= hight {vis_y = i; break;}
+        }
+        vis_x * vis_y * vis_xn * vis_yn
+    }
+    fn add_tree(&mut self, tree: Tree, hight: u8) {
+        self.width = (tree.x + 1).max(self.width);
+        self.hight = (tree.y + 1).max(self.hight);
+        self.trees.insert(tree, hight);
+    }
+    fn most_scenic(&self) -> (Tree, usize) {
+        let mut scenic = 0;
+        let mut scenic_tree = Tree::new(0, 0);
+        for tree in self.trees.keys() {
+            let new_scenic = self.scenic(tree);
+            if new_scenic > scenic {
+                scenic = new_scenic;
+                scenic_tree = *tree;
+            }
+        }
+        (scenic_tree, scenic)
+    }
+}
+
+impl Puzzle for Forest {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut forest = Forest::new();
+        for (y, line) in input.lines().map(str::trim).filter(|l| !l.is_empty()).enumerate() {
+            if y > 0 && line.len() != forest.width {
+                return Err(ParseError::at(INFO.number, input, line, format!("row is {} trees wide, the ones before are {}", line.len(), forest.width)));
+            }
+            for (x, char) in line.char_indices() {
+                let hight = char.to_digit(10).ok_or_else(|| ParseError::at(INFO.number, input, &line[x..], format!("{:?} is not a tree hight", char)))?;
+                forest.add_tree(Tree::new(x, y), hight as u8);
+            }
+        }
+        Ok(forest)
+    }
+
+    fn part1(&self) -> usize {
+        self.trees.keys().filter(|t| self.is_visible(t)).count()
+    }
+
+    fn part2(&self) -> usize {
+        self.most_scenic().1
+    }
+}
+
+impl Visualise<Forest> for Visualiser {
+    type Param = SRes<Trees>;
+
+    fn build(app: &mut App) {
+        crate::advent_calendar::init_seeded::<Trees>(app);
+    }
+
+    fn visualise(forest: Forest, commands: &mut Commands, trees: SystemParamItem<Self::Param>) {
+        info!("Forest is {}x{}", forest.width, forest.hight);
+        let root = commands.spawn((NodeBundle {
+            style: Style {
+                align_self: AlignSelf::Center,
+                position: UiRect::top(Val::Px(150.)),
+                size: Size::new(Val::Percent(85.), Val::Percent(85.)),
+                flex_wrap: FlexWrap::Wrap,
+                margin: UiRect::all(Val::Auto),
+                ..Default::default()
+            },
+            ..Default::default()
+        }, DayItem)).id();
+        for (_, hight) in forest.trees.iter().filter(|(t, _)| forest.is_visible(t)) {
+            commands.spawn(ImageBundle {
+                image: trees.0[*hight as usize].clone().into(),
+                style: Style {
+                    size: Size::new(Val::Percent(200./forest.width as f32), Val::Percent(400./forest.hight as f32)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }).set_parent(root);
+        }
+        let (scenic_tree, scenic) = forest.most_scenic();
+        info!("most scenic is {}:{} with a score of {}",scenic_tree.x, scenic_tree.y, scenic);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let forest = Forest::parse("30373\n25512\n65332\n33549\n35390").unwrap();
+        assert_eq!(forest.part1(), 21);
+        assert_eq!(forest.part2(), 8);
+    }
+
+    #[test]
+    fn errors() {
+        let error = Forest::parse("303\n2a5").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Forest::parse("303\n25").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,27 @@
+use super::{DEFAULT_DAY_COUNT, DayInfo, DayItem, Visualise, YearInfo, timeline};
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+
+const YEAR: i32 = 2022;
+
+pub(super) const INFO: YearInfo = YearInfo {
+    year: YEAR,
+    day_count: DEFAULT_DAY_COUNT,
+    days: &[
+        day1::INFO,
+        day2::INFO,
+        day3::INFO,
+        day4::INFO,
+        day5::INFO,
+        day6::INFO,
+        day7::INFO,
+        day8::INFO,
+    ],
+};
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use bevy::{prelude::*, sprite::Anchor, reflect::TypeUuid, asset::{AssetLoader, LoadedAsset}};
 use serde::Deserialize;
 
-use crate::advent_calendar::AdventData;
+use crate::advent_calendar::{self, AdventData};
 
 pub struct ElfPlugin;
 
@@ -12,7 +12,7 @@ impl Plugin for ElfPlugin {
         app.add_asset_loader(ElfPartLoader);
         app.add_asset::<Elf>();
         app.add_asset::<ElfPart>();
-        app.init_resource::<ElfParts>();
+        advent_calendar::init_seeded::<ElfParts>(app);
     }
 }
 
@@ -52,7 +52,7 @@ impl FromWorld for ElfParts {
         use rand::{seq::SliceRandom, SeedableRng};
         let asset_server = world.resource::<AssetServer>();
         let advent_data = world.resource::<AdventData>();
-        let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(advent_data.rng_seed());
         let mut heads :Vec<Handle<ElfPart>>= asset_server.load_folder("elf/head").unwrap().into_iter().map(|f| f.typed()).collect();
         let mut hats  :Vec<Handle<ElfPart>>= asset_server.load_folder("elf/hat").unwrap().into_iter().map(|f|  f.typed()).collect();
         let mut bodys :Vec<Handle<ElfPart>>= asset_server.load_folder("elf/body").unwrap().into_iter().map(|f| f.typed()).collect();
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+pub mod advent_calendar;
+pub mod days;
+pub mod elf;
+pub mod navigation;
+pub mod profiles;
+pub mod puzzle;
+pub mod save;
+pub mod seed;
+pub mod stats;
+pub mod unlock;
+mod utils;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum Screen {
+    /// Picking who is playing, shown before the calendar.
+    Profiles,
+    Calendar,
+    /// A day of an event, the year and the day.
+    Day(i32, u8),
+}
+
+impl Screen {
+    /// The year and day number of a day screen, `None` on the calendar and profiles.
+    pub fn day(&self) -> Option<(i32, u8)> {
+        match self {
+            Screen::Profiles | Screen::Calendar => None,
+            Screen::Day(year, day) => Some((*year, *day)),
+        }
+    }
+}
+
+pub fn cleanup<T: Component>(
+    mut commands: Commands,
+    query: Query<Entity, With<T>>,
+){
+    println!("Runing Cleanup");
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn spawn_cam(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+
+fn hide_ui<const TO: bool, T: Component>(
+    mut query: Query<&mut Visibility, With<T>>
+) {
+    for mut vis in &mut query {
+        vis.is_visible = TO;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+
+use crate::{Screen, advent_calendar::{self, AdventData, CalendarYear, Door}, days, profiles::Renaming, unlock::UnlockPolicy};
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DoorFocus>();
+        app.add_system(profile_navigation);
+        app.add_system(calendar_navigation);
+        app.add_system(day_navigation);
+        app.add_system(highlight_door);
+    }
+}
+
+/// The day of the door with focus on the calendar, it stays on the last day opened.
+#[derive(Resource, Default)]
+pub struct DoorFocus(pub Option<u8>);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Nav {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+    Previous,
+    Next,
+}
+
+const KEYS: [(KeyCode, Nav); 9] = [
+    (KeyCode::Up, Nav::Up),
+    (KeyCode::Down, Nav::Down),
+    (KeyCode::Left, Nav::Left),
+    (KeyCode::Right, Nav::Right),
+    (KeyCode::Return, Nav::Select),
+    (KeyCode::NumpadEnter, Nav::Select),
+    (KeyCode::Escape, Nav::Back),
+    (KeyCode::LBracket, Nav::Previous),
+    (KeyCode::RBracket, Nav::Next),
+];
+
+const BUTTONS: [(GamepadButtonType, Nav); 8] = [
+    (GamepadButtonType::DPadUp, Nav::Up),
+    (GamepadButtonType::DPadDown, Nav::Down),
+    (GamepadButtonType::DPadLeft, Nav::Left),
+    (GamepadButtonType::DPadRight, Nav::Right),
+    (GamepadButtonType::South, Nav::Select),
+    (GamepadButtonType::East, Nav::Back),
+    (GamepadButtonType::LeftTrigger, Nav::Previous),
+    (GamepadButtonType::RightTrigger, Nav::Next),
+];
+
+/// Everything pressed this frame, keys are left alone while an answer is being typed.
+fn pressed(keys: &Input<KeyCode>, buttons: &Input<GamepadButton>, gamepads: &Gamepads, typing: bool) -> Vec<Nav> {
+    let mut pressed = Vec::new();
+    if !typing {
+        pressed.extend(KEYS.iter().filter(|(key, _)| keys.just_pressed(*key)).map(|(_, nav)| *nav));
+    }
+    for gamepad in gamepads.iter() {
+        pressed.extend(BUTTONS.iter().filter(|(button, _)| buttons.just_pressed(GamepadButton::new(gamepad, *button))).map(|(_, nav)| *nav));
+    }
+    pressed
+}
+
+/// Days of `year` that are implemented and unlocked, in order.
+fn open_days(policy: &UnlockPolicy, year: i32) -> Vec<u8> {
+    let now = chrono::Utc::now();
+    days::solved_days(year).filter(|day| policy.is_unlocked(year, *day, now)).collect()
+}
+
+/// The open day before or after `day`, `day` itself when there is none.
+fn step_day(policy: &UnlockPolicy, year: i32, day: Option<u8>, nav: Nav) -> Option<u8> {
+    let open = open_days(policy, year);
+    match (nav, day) {
+        (Nav::Next, Some(day)) => open.iter().find(|d| **d > day).or(Some(&day)).copied(),
+        (Nav::Previous, Some(day)) => open.iter().rev().find(|d| **d < day).or(Some(&day)).copied(),
+        (Nav::Previous, None) => open.last().copied(),
+        _ => open.first().copied(),
+    }
+}
+
+fn profile_navigation(
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut state: ResMut<State<Screen>>,
+    mut advent_data: ResMut<AdventData>,
+    renaming: Res<Renaming>,
+) {
+    if *state.current() != Screen::Profiles {return;}
+    for nav in pressed(&keys, &buttons, &gamepads, renaming.is_renaming()) {
+        let active = advent_data.active();
+        match nav {
+            Nav::Up | Nav::Previous if active > 0 => advent_data.select(active - 1),
+            Nav::Down | Nav::Next if active + 1 < advent_data.profiles().len() => advent_data.select(active + 1),
+            Nav::Select => {
+                if let Err(e) = state.set(Screen::Calendar) {
+                    warn!("Failed to open the calendar: {:?}", e);
+                }
+                return;
+            },
+            _ => {},
+        }
+    }
+}
+
+fn calendar_navigation(
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut state: ResMut<State<Screen>>,
+    mut focus: ResMut<DoorFocus>,
+    doors: Query<&Door>,
+    policy: Res<UnlockPolicy>,
+    year: Res<CalendarYear>,
+) {
+    let year = year.0;
+    if *state.current() != Screen::Calendar || doors.is_empty() {return;}
+    for nav in pressed(&keys, &buttons, &gamepads, false) {
+        if nav == Nav::Back {
+            if let Err(e) = state.set(Screen::Profiles) {
+                warn!("Failed to open the profiles: {:?}", e);
+            }
+            return;
+        }
+        let Some(slot) = focus.0.and_then(|day| doors.iter().find(|door| door.day == day)).map(|door| door.slot) else {
+            // the first press only shows where the focus is
+            focus.0 = doors.iter().find(|door| door.slot == 0).map(|door| door.day);
+            continue;
+        };
+        let row = advent_calendar::doors_per_row(days::day_count(year));
+        let slot = match nav {
+            Nav::Up if slot >= row => slot - row,
+            Nav::Down => slot.saturating_add(row),
+            Nav::Left if slot % row != 0 => slot - 1,
+            Nav::Right if slot % row != row - 1 => slot + 1,
+            Nav::Previous | Nav::Next => {
+                focus.0 = step_day(&policy, year, focus.0, nav);
+                continue;
+            },
+            Nav::Select => {
+                let Some(day) = focus.0 else {continue;};
+                if days::registered(year, day).is_none() {
+                    info!("Day {} of {} is not implemented", day, year);
+                } else if !policy.is_unlocked(year, day, chrono::Utc::now()) {
+                    info!("Day {} of {} is still locked", day, year);
+                } else if let Err(e) = state.set(Screen::Day(year, day)) {
+                    warn!("Failed to open Day {}: {:?}", day, e);
+                }
+                return;
+            },
+
+            _ => slot,
+        };
+        if let Some(door) = doors.iter().find(|door| door.slot == slot) {
+            focus.0 = Some(door.day);
+        }
+    }
+}
+
+fn day_navigation(
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut state: ResMut<State<Screen>>,
+    mut focus: ResMut<DoorFocus>,
+    policy: Res<UnlockPolicy>,
+    answers: days::AnswerFocus,
+) {
+    let Some((year, day)) = state.current().day() else {return;};
+    if focus.0 != Some(day) {
+        focus.0 = Some(day);
+    }
+    for nav in pressed(&keys, &buttons, &gamepads, answers.is_typing()) {
+        let to = match nav {
+            Nav::Back => Screen::Calendar,
+            Nav::Previous | Nav::Next => match step_day(&policy, year, Some(day), nav) {
+                Some(next) if next != day => Screen::Day(year, next),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if let Err(e) = state.set(to) {
+            warn!("Failed to leave Day {}: {:?}", day, e);
+        }
+        return;
+    }
+}
+
+fn highlight_door(
+    focus: Res<DoorFocus>,
+    mut doors: Query<(&Door, &mut BackgroundColor)>,
+    added: Query<(), Added<Door>>,
+) {
+    if !focus.is_changed() && added.is_empty() {return;}
+    for (door, mut color) in &mut doors {
+        let wanted = if focus.0 == Some(door.day) {Color::GOLD} else {door.color};
+        if color.0 != wanted {
+            color.0 = wanted;
+        }
+    }
+}
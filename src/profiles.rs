@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+
+use crate::{Screen, advent_calendar::{AdventData, CalendarAssets, Stars}, seed, unlock::UnlockPolicy};
+
+pub struct ProfilesPlugin;
+
+impl Plugin for ProfilesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Renaming>();
+        app.add_system_set(SystemSet::on_update(Screen::Profiles)
+            .with_system(draw_profiles)
+            .with_system(profile_buttons)
+            .with_system(type_name)
+        )
+        .add_system_set(SystemSet::on_exit(Screen::Profiles)
+            .with_system(apply_settings)
+            .with_system(stop_renaming)
+            .with_system(crate::cleanup::<ProfileItem>)
+        );
+    }
+}
+
+#[derive(Component)]
+struct ProfileItem;
+
+/// The profile having its name typed in and the name so far.
+#[derive(Resource, Default)]
+pub struct Renaming(Option<(usize, String)>);
+
+impl Renaming {
+    pub fn is_renaming(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+#[derive(Component, Clone, Copy)]
+enum ProfileButton {
+    Play(usize),
+    Rename(usize),
+    Duplicate(usize),
+    Delete(usize),
+    UnlockAll(usize),
+    Reroll(usize),
+    CopySeed(usize),
+    New,
+}
+
+/// Everything is drawn again when a profile changes, there are never more than a handful.
+fn draw_profiles(
+    mut commands: Commands,
+    assets: Res<CalendarAssets>,
+    advent_data: Res<AdventData>,
+    renaming: Res<Renaming>,
+    drawn: Query<Entity, With<ProfileItem>>,
+) {
+    if !drawn.is_empty() && !advent_data.is_changed() && !renaming.is_changed() {return;}
+    for entity in &drawn {
+        commands.entity(entity).despawn_recursive();
+    }
+    let text = |value: String, font_size: f32| TextBundle::from_section(value, TextStyle { font: assets.text_font.clone(), font_size, color: Color::BLACK });
+    let button = |color: Color| ButtonBundle {
+        style: Style {
+            padding: UiRect::horizontal(Val::Px(5.)),
+            margin: UiRect::left(Val::Px(5.)),
+            ..Default::default()
+        },
+        background_color: color.into(),
+        ..Default::default()
+    };
+    commands.spawn((NodeBundle {
+        style: Style {
+            margin: UiRect::all(Val::Auto),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(10.)),
+            ..Default::default()
+        },
+        background_color: Color::rgba(1., 1., 1., 0.8).into(),
+        ..Default::default()
+    }, ProfileItem)).with_children(|p| {
+        p.spawn(text("Who is playing?".to_string(), 40.));
+        for (index, profile) in advent_data.profiles().iter().enumerate() {
+            p.spawn(NodeBundle {
+                style: Style {
+                    align_items: AlignItems::Center,
+                    margin: UiRect::vertical(Val::Px(3.)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }).with_children(|p| {
+                let name = match &renaming.0 {
+                    Some((renaming, name)) if *renaming == index => format!("{}|", name),
+                    _ => profile.name.clone(),
+                };
+                let color = if index == advent_data.active() {Color::GOLD} else {Color::WHITE};
+                p.spawn((ButtonBundle {
+                    style: Style {
+                        min_size: Size::new(Val::Px(250.), Val::Auto),
+                        padding: UiRect::horizontal(Val::Px(5.)),
+                        ..Default::default()
+                    },
+                    background_color: color.into(),
+                    ..Default::default()
+                }, ProfileButton::Play(index))).with_children(|p| {
+                    p.spawn(text(name, 25.));
+                });
+                let stars: usize = profile.stars.values().map(Stars::count).sum();
+                p.spawn(text(format!("{} stars", stars), 20.)).insert(Style {
+                    min_size: Size::new(Val::Px(90.), Val::Auto),
+                    margin: UiRect::left(Val::Px(5.)),
+                    ..Default::default()
+                });
+                let pinned = advent_data.pinned_seed().filter(|_| index == advent_data.active());
+                let seed = match pinned {
+                    Some(seed) => format!("Seed {} pinned", seed::code(seed)),
+                    None => format!("Seed {}", seed::code(profile.rng_seed)),
+                };
+                p.spawn((button(Color::GRAY), ProfileButton::CopySeed(index))).with_children(|p| {
+                    p.spawn(text(seed, 20.));
+                });
+                let unlock = if profile.settings.unlock_all {"All open"} else {"By date"};
+                for (action, label) in [(ProfileButton::Rename(index), "Rename"), (ProfileButton::Duplicate(index), "Copy"), (ProfileButton::Delete(index), "Delete"), (ProfileButton::UnlockAll(index), unlock), (ProfileButton::Reroll(index), "Reroll")] {
+                    p.spawn((button(Color::GRAY), action)).with_children(|p| {
+                        p.spawn(text(label.to_string(), 20.));
+                    });
+                }
+            });
+        }
+        p.spawn((button(Color::GRAY), ProfileButton::New)).with_children(|p| {
+            p.spawn(text("New profile".to_string(), 20.));
+        });
+    });
+}
+
+fn profile_buttons(
+    query: Query<(&Interaction, &ProfileButton), Changed<Interaction>>,
+    mut advent_data: ResMut<AdventData>,
+    mut renaming: ResMut<Renaming>,
+    mut state: ResMut<State<Screen>>,
+) {
+    for (interaction, button) in &query {
+        if *interaction != Interaction::Clicked {continue;}
+        match *button {
+            ProfileButton::Play(index) => {
+                if renaming.is_renaming() {continue;}
+                advent_data.select(index);
+                if let Err(e) = state.set(Screen::Calendar) {
+                    warn!("Failed to open the calendar: {:?}", e);
+                }
+                return;
+            },
+            ProfileButton::Rename(index) => {
+                renaming.0 = Some((index, advent_data.profiles()[index].name.clone()));
+            },
+            ProfileButton::Duplicate(index) => {
+                advent_data.duplicate(index);
+                renaming.0 = None;
+            },
+            ProfileButton::Delete(index) => {
+                if !advent_data.delete(index) {
+                    info!("The last profile can not be deleted");
+                }
+                renaming.0 = None;
+            },
+            ProfileButton::UnlockAll(index) => {
+                let settings = &mut advent_data.profiles_mut()[index].settings;
+                settings.unlock_all = !settings.unlock_all;
+            },
+            ProfileButton::Reroll(index) => {
+                advent_data.reroll(index);
+            },
+            ProfileButton::CopySeed(index) => {
+                let seed = if index == advent_data.active() {advent_data.rng_seed()} else {advent_data.profiles()[index].rng_seed};
+                let code = seed::code(seed);
+                match arboard::Clipboard::new().and_then(|mut c| c.set_text(code.clone())) {
+                    Ok(()) => info!("Copied seed {}", code),
+                    Err(e) => warn!("Failed to copy seed: {}", e),
+                }
+            },
+            ProfileButton::New => {
+                let index = advent_data.create();
+                advent_data.select(index);
+                renaming.0 = Some((index, String::new()));
+            },
+        }
+    }
+}
+
+fn type_name(
+    mut chars: EventReader<ReceivedCharacter>,
+    mut keys: ResMut<Input<KeyCode>>,
+    mut renaming: ResMut<Renaming>,
+    mut advent_data: ResMut<AdventData>,
+) {
+    let Some((index, name)) = renaming.0.clone() else {chars.clear(); return;};
+    // the keys are used up here so they do not also pick a profile
+    if keys.clear_just_pressed(KeyCode::Escape) {
+        renaming.0 = None;
+        return;
+    }
+    if keys.clear_just_pressed(KeyCode::Return) | keys.clear_just_pressed(KeyCode::NumpadEnter) {
+        if advent_data.rename(index, &name) {
+            renaming.0 = None;
+        } else {
+            warn!("{:?} is empty or already taken", name);
+        }
+        return;
+    }
+    let mut typed = name.clone();
+    typed.extend(chars.iter().map(|c| c.char).filter(|c| !c.is_control()));
+    if keys.just_pressed(KeyCode::Back) {
+        typed.pop();
+    }
+    if typed != name {
+        renaming.0 = Some((index, typed));
+    }
+}
+
+fn stop_renaming(mut renaming: ResMut<Renaming>) {
+    renaming.0 = None;
+}
+
+/// A profiles `unlock_all` goes on top of the policy the app started with.
+fn apply_settings(
+    advent_data: Res<AdventData>,
+    mut policy: ResMut<UnlockPolicy>,
+    mut base: Local<Option<bool>>,
+) {
+    let base = *base.get_or_insert(policy.unlock_all);
+    let unlock_all = base || advent_data.profile().settings.unlock_all;
+    if policy.unlock_all != unlock_all {
+        policy.unlock_all = unlock_all;
+    }
+}
@@ -0,0 +1,148 @@
+use std::{fmt::Display, time::{Duration, Instant}};
+
+use serde::{Deserialize, Serialize};
+
+/// A days solver, kept free of bevy so it can be run and tested without an `App`.
+pub trait Puzzle: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+    fn part1(&self) -> Self::Part1;
+    fn part2(&self) -> Self::Part2;
+}
+
+/// Why an input could not be parsed, lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// `part` has to be a slice of `input`, the line and column are worked out from where it sits.
+    pub fn at(day: u8, input: &str, part: &str, message: impl Into<String>) -> ParseError {
+        let offset = (part.as_ptr() as usize).saturating_sub(input.as_ptr() as usize).min(input.len());
+        let before = input.get(..offset).unwrap_or("");
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} line {} column {}: {}", self.day, self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// What every day reports back once it has been solved.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    pub part1: Option<String>,
+    #[serde(default)]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `input` and runs a single part, for when the model type does not matter to the caller.
+pub type Solver = fn(&str, u8) -> Result<String, String>;
+
+pub fn solve<P: Puzzle>(input: &str, part: u8) -> Result<String, String> {
+    let puzzle = P::parse(input).map_err(|e| e.to_string())?;
+    match part {
+        1 => Ok(puzzle.part1().to_string()),
+        2 => Ok(puzzle.part2().to_string()),
+        _ => Err(format!("There is no part {}", part)),
+    }
+}
+
+/// How long each step of a solve took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Solves both parts, timing the parse and each part on its own.
+pub fn timed<P: Puzzle>(input: &str) -> Result<(P, Answers, Timings), ParseError> {
+    let start = Instant::now();
+    let puzzle = P::parse(input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let part1 = puzzle.part1();
+    let part1_time = start.elapsed();
+    let start = Instant::now();
+    let part2 = puzzle.part2();
+    let part2_time = start.elapsed();
+    let answers = Answers { part1: Some(part1.to_string()), part2: Some(part2.to_string()) };
+    Ok((puzzle, answers, Timings { parse, part1: part1_time, part2: part2_time }))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn of(mut times: Vec<Duration>) -> Stats {
+        times.sort();
+        Stats {
+            min: times.first().copied().unwrap_or_default(),
+            median: times.get(times.len() / 2).copied().unwrap_or_default(),
+            max: times.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Runs a day `runs` times, like `Solver` it hides the model type.
+pub type Bencher = fn(&str, usize) -> Result<Bench, ParseError>;
+
+pub fn bench<P: Puzzle>(input: &str, runs: usize) -> Result<Bench, ParseError> {
+    let runs = runs.max(1);
+    let mut timings = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let (_, _, time) = timed::<P>(input)?;
+        timings.push(time);
+    }
+    Ok(Bench {
+        runs,
+        parse: Stats::of(timings.iter().map(|t| t.parse).collect()),
+        part1: Stats::of(timings.iter().map(|t| t.part1).collect()),
+        part2: Stats::of(timings.iter().map(|t| t.part2).collect()),
+    })
+}
@@ -0,0 +1,355 @@
+use std::{collections::BTreeMap, io::Write, path::{Path, PathBuf}};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::advent_calendar::{AdventData, Profile, Settings, Stars};
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(CoreStage::Last, save_on_change);
+    }
+}
+
+/// Whether changes get written back, saves that could not be read are left alone.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SaveMode {
+    Write,
+    /// The save is from a newer build or could not be moved aside, this session only lives in memory.
+    ReadOnly,
+}
+
+const SAVE_VERSION: u32 = 5;
+const SAVE_NAME: &str = "advent.dat";
+
+#[derive(Deserialize)]
+struct Header {
+    #[serde(default)]
+    version: u32,
+}
+
+#[derive(Serialize)]
+struct SaveFileRef<'a> {
+    version: u32,
+    data: &'a AdventData,
+}
+
+#[derive(Deserialize)]
+struct SaveFile<T> {
+    data: T,
+}
+
+/// `AdventData` from before version 2, when stars had no time they were earned.
+#[derive(Deserialize)]
+struct LegacyAdventData {
+    rng_seed: u64,
+    stars: LegacyStars,
+}
+
+#[derive(Deserialize)]
+struct LegacyStars([[bool; 2]; 25]);
+
+impl From<LegacyAdventData> for Profile {
+    fn from(legacy: LegacyAdventData) -> Self {
+        SingleProfile { rng_seed: legacy.rng_seed, stars: FixedStars { gold: legacy.stars.0, earned: Default::default() } }.into()
+    }
+}
+
+/// `Stars` from before version 5, when every event had 25 days.
+#[derive(Deserialize)]
+struct FixedStars {
+    gold: [[bool; 2]; 25],
+    earned: [[Option<i64>; 2]; 25],
+}
+
+impl From<FixedStars> for Stars {
+    fn from(stars: FixedStars) -> Self {
+        Stars::from_days(stars.gold.to_vec(), stars.earned.to_vec())
+    }
+}
+
+/// `AdventData` from version 2, before there were profiles.
+#[derive(Deserialize)]
+struct SingleProfile {
+    rng_seed: u64,
+    stars: FixedStars,
+}
+
+impl From<SingleProfile> for Profile {
+    fn from(single: SingleProfile) -> Self {
+        FixedProfile { name: "Player 1".to_string(), rng_seed: single.rng_seed, stars: single.stars, settings: Settings::default() }.into()
+    }
+}
+
+/// Stars from before version 4 are all from the only event there was then.
+const FIRST_YEAR: i32 = 2022;
+
+/// `AdventData` from versions 3 and 4, `S` is one years `FixedStars` in 3 and a map of them by year in 4.
+#[derive(Deserialize)]
+struct FixedData<S> {
+    active: usize,
+    profiles: Vec<FixedProfile<S>>,
+}
+
+#[derive(Deserialize)]
+struct FixedProfile<S> {
+    name: String,
+    rng_seed: u64,
+    stars: S,
+    #[serde(default)]
+    settings: Settings,
+}
+
+impl From<FixedProfile<FixedStars>> for Profile {
+    fn from(profile: FixedProfile<FixedStars>) -> Self {
+        FixedProfile { name: profile.name, rng_seed: profile.rng_seed, stars: BTreeMap::from([(FIRST_YEAR, profile.stars)]), settings: profile.settings }.into()
+    }
+}
+
+impl From<FixedProfile<BTreeMap<i32, FixedStars>>> for Profile {
+    fn from(profile: FixedProfile<BTreeMap<i32, FixedStars>>) -> Self {
+        let stars = profile.stars.into_iter().map(|(year, stars)| (year, stars.into())).collect();
+        Profile { name: profile.name, rng_seed: profile.rng_seed, stars, settings: profile.settings }
+    }
+}
+
+impl<S> From<FixedData<S>> for AdventData where FixedProfile<S>: Into<Profile> {
+    fn from(data: FixedData<S>) -> Self {
+        AdventData::from_profiles(data.active, data.profiles.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    Write(ron::Error),
+    TooNew(u32),
+}
+
+impl From<std::io::Error> for SaveError {
+    fn from(e: std::io::Error) -> Self {
+        SaveError::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for SaveError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        SaveError::Parse(e)
+    }
+}
+
+impl From<ron::Error> for SaveError {
+    fn from(e: ron::Error) -> Self {
+        SaveError::Write(e)
+    }
+}
+
+/// Where the save lives, falling back to the working directory if the platform has no data directory.
+pub fn save_path() -> PathBuf {
+    match dirs::data_dir() {
+        Some(dir) => dir.join("advent_of_bevy").join(SAVE_NAME),
+        None => PathBuf::from(".").join(SAVE_NAME),
+    }
+}
+
+/// Saves used to be written to the working directory before they had a version.
+fn legacy_path() -> PathBuf {
+    PathBuf::from(".").join(SAVE_NAME)
+}
+
+fn parse(data: &str) -> Result<AdventData, SaveError> {
+    let header: Header = ron::from_str(data)?;
+    match header.version {
+        0 => Ok(Profile::from(ron::from_str::<LegacyAdventData>(data)?).into()),
+        1 => Ok(Profile::from(ron::from_str::<SaveFile<LegacyAdventData>>(data)?.data).into()),
+        2 => Ok(Profile::from(ron::from_str::<SaveFile<SingleProfile>>(data)?.data).into()),
+        3 => Ok(ron::from_str::<SaveFile<FixedData<FixedStars>>>(data)?.data.into()),
+        4 => Ok(ron::from_str::<SaveFile<FixedData<BTreeMap<i32, FixedStars>>>>(data)?.data.into()),
+        SAVE_VERSION => Ok(ron::from_str::<SaveFile<AdventData>>(data)?.data.checked()),
+        version => Err(SaveError::TooNew(version)),
+    }
+}
+
+pub fn load() -> (AdventData, SaveMode) {
+    let path = save_path();
+    let source = if path.exists() || !legacy_path().exists() {path.clone()} else {legacy_path()};
+    let Ok(data) = std::fs::read_to_string(&source) else {
+        return fresh();
+    };
+    match parse(&data) {
+        Ok(advent_data) => {
+            if source != path {
+                info!("Moving save from {} to {}", source.display(), path.display());
+                if let Err(e) = save(&advent_data) {error!("Failed to save: {:?}", e);}
+            }
+            (advent_data, SaveMode::Write)
+        },
+        Err(SaveError::TooNew(version)) => {
+            warn!("Save at {} is version {} which is newer than this build, nothing will be saved", source.display(), version);
+            (AdventData::new(), SaveMode::ReadOnly)
+        },
+        Err(e) => {
+            error!("Save at {} could not be read: {:?}", source.display(), e);
+            if move_aside(&source) {
+                fresh()
+            } else {
+                warn!("Nothing will be saved so {} is not overwritten", source.display());
+                (AdventData::new(), SaveMode::ReadOnly)
+            }
+        }
+    }
+}
+
+fn fresh() -> (AdventData, SaveMode) {
+    let advent_data = AdventData::new();
+    if let Err(e) = save(&advent_data) {
+        error!("Failed to save: {:?}", e);
+    }
+    (advent_data, SaveMode::Write)
+}
+
+fn move_aside(path: &Path) -> bool {
+    let mut aside = path.as_os_str().to_owned();
+    aside.push(format!(".corrupt-{}", chrono::Utc::now().format("%Y%m%d%H%M%S")));
+    match std::fs::rename(path, &aside) {
+        Ok(()) => {
+            warn!("Moved unreadable save to {}", Path::new(&aside).display());
+            true
+        },
+        Err(e) => {
+            error!("Failed to move unreadable save aside: {}", e);
+            false
+        },
+    }
+}
+
+/// Writes to a temp file first so a crash mid write never leaves a half written save behind.
+pub fn save(advent_data: &AdventData) -> Result<(), SaveError> {
+    let path = save_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let data = ron::to_string(&SaveFileRef { version: SAVE_VERSION, data: advent_data })?;
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let mut file = std::fs::File::create(&tmp)?;
+    file.write_all(data.as_bytes())?;
+    file.sync_all()?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+fn save_on_change(advent_data: Res<AdventData>, mode: Option<Res<SaveMode>>) {
+    if !advent_data.is_changed() || advent_data.is_added() {return;}
+    if mode.map_or(false, |mode| *mode == SaveMode::ReadOnly) {return;}
+    if let Err(e) = save(&advent_data) {
+        error!("Failed to save: {:?}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advent_calendar::AdventStar;
+
+    /// 25 days written the way ron writes a fixed array, `first` is day 1.
+    fn days(first: &str, rest: &str) -> String {
+        format!("({},{})", first, vec![rest; 24].join(","))
+    }
+
+    fn star(year: i32, day: u8, star: u8) -> AdventStar {
+        AdventStar { year, day, star }
+    }
+
+    #[test]
+    fn version_0() {
+        let data = parse(&format!("(rng_seed:7,stars:({}))", days("(true,false)", "(false,false)"))).unwrap();
+        let profile = data.profile();
+        assert_eq!(profile.rng_seed, 7);
+        assert!(profile.is_gold(&star(FIRST_YEAR, 0, 0)));
+        assert!(!profile.is_gold(&star(FIRST_YEAR, 0, 1)));
+        assert_eq!(profile.stars(FIRST_YEAR).earned(&star(FIRST_YEAR, 0, 0)), None);
+        assert_eq!(profile.stars.keys().copied().collect::<Vec<_>>(), vec![FIRST_YEAR]);
+    }
+
+    #[test]
+    fn version_1() {
+        let data = parse(&format!("(version:1,data:(rng_seed:8,stars:({})))", days("(true,true)", "(false,false)"))).unwrap();
+        let profile = data.profile();
+        assert_eq!(profile.rng_seed, 8);
+        assert_eq!(profile.stars(FIRST_YEAR).count(), 2);
+        assert_eq!(profile.stars(FIRST_YEAR).earned(&star(FIRST_YEAR, 0, 1)), None);
+    }
+
+    #[test]
+    fn version_2() {
+        let gold = days("(true,false)", "(false,false)");
+        let earned = days("(Some(1670000000),None)", "(None,None)");
+        let data = parse(&format!("(version:2,data:(rng_seed:9,stars:(gold:{},earned:{})))", gold, earned)).unwrap();
+        let profile = data.profile();
+        assert_eq!(profile.name, "Player 1");
+        assert_eq!(profile.rng_seed, 9);
+        assert!(profile.is_gold(&star(FIRST_YEAR, 0, 0)));
+        assert_eq!(profile.stars(FIRST_YEAR).earned(&star(FIRST_YEAR, 0, 0)).map(|time| time.timestamp()), Some(1670000000));
+    }
+
+    #[test]
+    fn version_3() {
+        let gold = days("(false,false)", "(false,true)");
+        let earned = days("(None,None)", "(None,Some(1670000000))");
+        let data = parse(&format!("(version:3,data:(active:1,profiles:[(name:\"A\",rng_seed:1,stars:(gold:{gold},earned:{earned})),(name:\"B\",rng_seed:2,stars:(gold:{gold},earned:{earned}),settings:(unlock_all:true,playback_speed:2.0))]))")).unwrap();
+        assert_eq!(data.active(), 1);
+        assert_eq!(data.profiles()[0].name, "A");
+        assert!(!data.profiles()[0].settings.unlock_all);
+        let profile = data.profile();
+        assert_eq!(profile.rng_seed, 2);
+        assert!(profile.settings.unlock_all);
+        assert_eq!(profile.stars(FIRST_YEAR).count(), 24);
+        assert!(profile.is_gold(&star(FIRST_YEAR, 24, 1)));
+        assert_eq!(profile.stars(FIRST_YEAR).earned(&star(FIRST_YEAR, 24, 1)).map(|time| time.timestamp()), Some(1670000000));
+    }
+
+    #[test]
+    fn version_4() {
+        let gold = days("(true,false)", "(false,false)");
+        let earned = days("(Some(1670000000),None)", "(None,None)");
+        let none = days("(false,false)", "(false,false)");
+        let never = days("(None,None)", "(None,None)");
+        let data = parse(&format!("(version:4,data:(active:0,profiles:[(name:\"A\",rng_seed:3,stars:{{2022:(gold:{gold},earned:{earned}),2023:(gold:{none},earned:{never})}})]))")).unwrap();
+        let profile = data.profile();
+        assert_eq!(profile.rng_seed, 3);
+        assert_eq!(profile.stars.keys().copied().collect::<Vec<_>>(), vec![2022, 2023]);
+        assert!(profile.is_gold(&star(2022, 0, 0)));
+        assert!(!profile.is_gold(&star(2023, 0, 0)));
+        assert_eq!(profile.stars(2022).earned(&star(2022, 0, 0)).map(|time| time.timestamp()), Some(1670000000));
+    }
+
+    #[test]
+    fn version_5() {
+        let data = parse("(version:5,data:(active:0,profiles:[(name:\"A\",rng_seed:4,stars:{2024:(gold:[(false,false),(false,true)],earned:[(None,None),(None,Some(1733000000))])},settings:(unlock_all:false,playback_speed:1.0))]))").unwrap();
+        let profile = data.profile();
+        assert_eq!(profile.rng_seed, 4);
+        assert!(profile.is_gold(&star(2024, 1, 1)));
+        assert!(!profile.is_gold(&star(2024, 12, 0)));
+        assert!(!profile.is_gold(&star(FIRST_YEAR, 1, 1)));
+        assert_eq!(profile.stars(2024).earned(&star(2024, 1, 1)).map(|time| time.timestamp()), Some(1733000000));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut data = AdventData::new();
+        data.profile_mut().set_gold(&star(2022, 3, 1));
+        let written = ron::to_string(&SaveFileRef { version: SAVE_VERSION, data: &data }).unwrap();
+        let read = parse(&written).unwrap();
+        assert_eq!(read.profile().rng_seed, data.profile().rng_seed);
+        assert!(read.profile().is_gold(&star(2022, 3, 1)));
+    }
+
+    #[test]
+    fn too_new() {
+        assert!(matches!(parse("(version:99,data:())"), Err(SaveError::TooNew(99))));
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A seed written in base 36, at most 13 letters and digits so it is easy to paste into a bug report.
+pub fn code(seed: u64) -> String {
+    let mut seed = seed;
+    let mut code = Vec::new();
+    loop {
+        code.push(DIGITS[(seed % 36) as usize]);
+        seed /= 36;
+        if seed == 0 {break;}
+    }
+    code.reverse();
+    String::from_utf8(code).unwrap_or_default()
+}
+
+/// Reads a code made by `code`, case and any spaces or dashes are ignored.
+pub fn parse(code: &str) -> Option<u64> {
+    let mut seed: u64 = 0;
+    let mut digits = 0;
+    for c in code.chars().filter(|c| !matches!(c, ' ' | '-')) {
+        seed = seed.checked_mul(36)?.checked_add(c.to_digit(36)? as u64)?;
+        digits += 1;
+    }
+    (digits > 0).then_some(seed)
+}
+
+/// The seed code from `--seed <CODE>` or `AOB_SEED`, used instead of the profiles seed until it is rerolled.
+pub fn pinned() -> Option<u64> {
+    let mut args = std::env::args().skip_while(|arg| arg != "--seed").skip(1);
+    let code = args.next().or_else(|| std::env::var("AOB_SEED").ok())?;
+    let seed = parse(&code);
+    match seed {
+        Some(seed) => info!("Seed pinned to {}", self::code(seed)),
+        None => warn!("{:?} is not a seed code", code),
+    }
+    seed
+}
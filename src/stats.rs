@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::{advent_calendar::{AdventData, AdventStar, CalendarAssets, CalendarYear, CalenderItem, Stars}, days::{self, SolveTimes}, seed, unlock::UnlockPolicy};
+
+/// The first and last day of each week of an event with `count` days, the last week stops early.
+fn weeks(count: u8) -> impl Iterator<Item = (u8, u8)> {
+    (1..=count).step_by(7).map(move |first| (first, first.saturating_add(6).min(count)))
+}
+
+/// Days in a row with both stars, counting back from the newest day. Today is not counted until it is done.
+pub fn streak(stars: &Stars, newest: u8) -> usize {
+    let newest = if newest > 0 && !stars.is_complete(newest - 1) {newest - 1} else {newest};
+    (1..=newest).rev().take_while(|day| stars.is_complete(day - 1)).count()
+}
+
+pub(crate) fn spawn_stats(
+    mut commands: Commands,
+    assets: Res<CalendarAssets>,
+    advent_data: Res<AdventData>,
+    solve_times: Res<SolveTimes>,
+    policy: Res<UnlockPolicy>,
+    year: Res<CalendarYear>,
+) {
+    let now = chrono::Utc::now();
+    let year = year.0;
+    let stars = advent_data.profile().stars(year);
+    let count = days::day_count(year);
+    let newest = (1..=count).rev().find(|day| policy.is_unlocked(year, *day, now)).unwrap_or(0);
+    let mut lines = vec![
+        (format!("{} Stars: {}/{}", year, stars.count(), count as usize * 2), Color::BLACK),
+        (format!("Streak: {} days", streak(stars, newest)), Color::BLACK),
+        (format!("Seed: {}", seed::code(advent_data.rng_seed())), Color::DARK_GRAY),
+    ];
+    for (week, (first, last)) in weeks(count).enumerate() {
+        let earned: usize = (first..=last).map(|day| (0..2).filter(|star| stars.is_gold(&AdventStar { year, day: day - 1, star: *star })).count()).sum();
+        lines.push((format!("Week {}: {}/{}", week + 1, earned, (last - first + 1) * 2), Color::BLACK));
+    }
+    let times = || solve_times.0.iter().filter(|((y, _), _)| *y == year).map(|((_, day), timings)| (day, timings));
+    let fastest = times().min_by_key(|(_, timings)| timings.total());
+    let slowest = times().max_by_key(|(_, timings)| timings.total());
+    if let (Some((fast_day, fast)), Some((slow_day, slow))) = (fastest, slowest) {
+        lines.push((format!("Fastest: Day {} {:.1?}", fast_day, fast.total()), Color::DARK_GREEN));
+        lines.push((format!("Slowest: Day {} {:.1?}", slow_day, slow.total()), Color::MAROON));
+    }
+    for day in 1..=count {
+        let earned: Vec<String> = (0..2).filter(|star| stars.is_gold(&AdventStar { year, day: day - 1, star: *star })).map(|star| {
+            match stars.earned(&AdventStar { year, day: day - 1, star }) {
+                Some(time) => format!("*{} {}", star + 1, time.with_timezone(&chrono::Local).format("%m-%d %H:%M")),
+                None => format!("*{}", star + 1),
+            }
+        }).collect();
+        let timings = solve_times.0.get(&(year, day));
+        if earned.is_empty() && timings.is_none() {continue;}
+        lines.push((format!("Day {:02} {}", day, earned.join("  ")), Color::DARK_GRAY));
+        if let Some(timings) = timings {
+            lines.push((format!("    p1 {:.1?}  p2 {:.1?}", timings.part1, timings.part2), Color::DARK_GRAY));
+        }
+    }
+    commands.spawn((NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: UiRect::new(Val::Px(10.), Val::Auto, Val::Px(10.), Val::Auto),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(5.)),
+            ..Default::default()
+        },
+        background_color: Color::rgba(1., 1., 1., 0.8).into(),
+        ..Default::default()
+    }, CalenderItem)).with_children(|p| {
+        for (line, color) in lines {
+            p.spawn(TextBundle {
+                text: Text { sections: vec![TextSection {
+                    value: line,
+                    style: TextStyle { font: assets.text_font.clone(), font_size: 15., color },
+                }], alignment: TextAlignment::default() },
+                ..Default::default()
+            });
+        }
+    });
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Utc};
+
+use crate::days;
+
+/// Puzzles unlock at midnight US Eastern, which is UTC-5 all through December.
+pub const EASTERN_OFFSET_HOURS: i32 = -5;
+
+/// When each door on the calendar opens. Insert one before `AdventPlugin` to change it,
+/// or set `AOB_YEAR`, `AOB_UTC_OFFSET` (hours) and `AOB_UNLOCK_ALL`.
+#[derive(Resource, Debug, Clone)]
+pub struct UnlockPolicy {
+    /// The event being played, the calendar opens on it when it has days.
+    pub year: i32,
+    pub utc_offset_hours: i32,
+    pub unlock_all: bool,
+}
+
+impl Default for UnlockPolicy {
+    fn default() -> Self {
+        UnlockPolicy::from_env()
+    }
+}
+
+impl UnlockPolicy {
+    /// The event being played right now with puzzles opening at midnight US Eastern.
+    pub fn current_event() -> UnlockPolicy {
+        let now = Utc::now().with_timezone(&offset(EASTERN_OFFSET_HOURS));
+        // before December the last event is the one being played
+        let year = if now.month() == 12 {now.year()} else {now.year() - 1};
+        UnlockPolicy { year, utc_offset_hours: EASTERN_OFFSET_HOURS, unlock_all: false }
+    }
+
+    pub fn from_env() -> UnlockPolicy {
+        let mut policy = UnlockPolicy::current_event();
+        if let Some(year) = env("AOB_YEAR") {
+            policy.year = year;
+        }
+        if let Some(hours) = env::<i32>("AOB_UTC_OFFSET") {
+            policy.utc_offset_hours = hours.clamp(-23, 23);
+        }
+        if let Ok(all) = std::env::var("AOB_UNLOCK_ALL") {
+            policy.unlock_all = !matches!(all.as_str(), "" | "0" | "false");
+        }
+        policy
+    }
+
+    /// Midnight at the start of December `day` of `year` in the policies time zone.
+    pub fn unlock_time(&self, year: i32, day: u8) -> DateTime<Utc> {
+        offset(self.utc_offset_hours)
+            .with_ymd_and_hms(year, 12, day.clamp(1, days::day_count(year).max(1)) as u32, 0, 0, 0)
+            .single()
+            .map(|time| time.with_timezone(&Utc))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+
+    pub fn is_unlocked(&self, year: i32, day: u8, now: DateTime<Utc>) -> bool {
+        self.unlock_all || now >= self.unlock_time(year, day)
+    }
+
+    /// The next day of `year` to unlock and how long until it does.
+    pub fn next_unlock(&self, year: i32, now: DateTime<Utc>) -> Option<(u8, Duration)> {
+        if self.unlock_all {return None;}
+        (1..=days::day_count(year)).find(|day| !self.is_unlocked(year, *day, now)).map(|day| (day, self.unlock_time(year, day) - now))
+    }
+}
+
+fn offset(hours: i32) -> FixedOffset {
+    FixedOffset::east_opt(hours * 3600).unwrap_or(FixedOffset::east_opt(0).unwrap())
+}
+
+fn env<T: std::str::FromStr>(name: &str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    match value.parse() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            warn!("{} is set to {:?} which is not a number", name, value);
+            None
+        }
+    }
+}
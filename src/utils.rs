@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 use png;
 #[test]
+#[ignore = "generates elf parts from local files"]
 fn gen_legs() {
     for _ in 0..100 {
         test_png("y:/youtube_bevy/bevy_advent_of_code/assets/elf/img/legs0_t.png", "legs", r#"(
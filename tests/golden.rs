@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use advent_of_bevy::days::{self, Day};
+
+/// Runs every input in `assets/<year>/days`, and any private inputs, through its solver and checks the answers stored with them.
+#[test]
+fn golden_answers() {
+    let mut failures = Vec::new();
+    for year in days::years() {
+        check_year(year, &mut failures);
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+fn check_year(year: i32, failures: &mut Vec<String>) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join(year.to_string()).join("days");
+    let mut checked = Vec::new();
+    let Ok(entries) = std::fs::read_dir(&dir) else {failures.push(format!("{} has days but no {}", year, dir.display())); return;};
+    let mut files: Vec<_> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    files.sort();
+    for path in files {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {continue;};
+        let Some(number) = name.strip_prefix("day").and_then(|n| n.strip_suffix(".day.ron")) else {continue;};
+        let Ok(number) = number.parse::<u8>() else {failures.push(format!("{}: not named dayN.day.ron", name)); continue;};
+        let mut day = match Day::from_ron(&std::fs::read_to_string(&path).unwrap()) {
+            Ok(day) => day,
+            Err(e) => {failures.push(format!("{}: {}", name, e)); continue;},
+        };
+        if let Err(e) = day.load_files(&dir) {
+            failures.push(e);
+        }
+        // private inputs are checked too when they are on this machine
+        if let Err(e) = day.load_private_input(Path::new(env!("CARGO_MANIFEST_DIR")), year, number) {
+            failures.push(e);
+        }
+        let Some(solver) = days::solver(year, number) else {failures.push(format!("Day {} of {} has no solver", number, year)); continue;};
+        checked.push(number);
+        let mut answered = false;
+        for input in day.inputs.iter() {
+            for part in 1..=2 {
+                let Some(expected) = input.answers.part(part) else {continue;};
+                answered = true;
+                match solver(&input.data, part) {
+                    Ok(answer) if answer == expected => {},
+                    Ok(answer) => failures.push(format!("Day {} of {} {:?} part {}: expected {} got {}", number, year, input.name, part, expected, answer)),
+                    Err(e) => failures.push(format!("Day {} of {} {:?} part {}: {}", number, year, input.name, part, e)),
+                }
+            }
+        }
+        if !answered {
+            failures.push(format!("Day {} of {} has no answers to check", number, year));
+        }
+    }
+    for day in days::solved_days(year) {
+        if !checked.contains(&day) {
+            failures.push(format!("Day {} of {} has no day asset", day, year));
+        }
+    }
+}